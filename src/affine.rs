@@ -0,0 +1,233 @@
+use crate::{Contour, Point2, Shape, Vector2};
+use core::ops::{Mul, MulAssign};
+
+/// 2D affine transformation
+///
+/// The transformation maps point `(x, y)` to `(xx * x + xy * y + dx, yx * x + yy * y + dy)`.
+///
+/// Transformations are composed like matrices: `a * b` applies `b` first and `a` next.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine2 {
+    pub xx: f64,
+    pub xy: f64,
+    pub yx: f64,
+    pub yy: f64,
+    pub dx: f64,
+    pub dy: f64,
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine2 {
+    /// Identity transformation
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    /// Creates transformation from coefficients
+    pub const fn new(xx: f64, xy: f64, yx: f64, yy: f64, dx: f64, dy: f64) -> Self {
+        Self {
+            xx,
+            xy,
+            yx,
+            yy,
+            dx,
+            dy,
+        }
+    }
+
+    /// Creates translation by offset
+    pub fn translate(offset: impl Into<Vector2<f64>>) -> Self {
+        let offset = offset.into();
+        Self::new(1.0, 0.0, 0.0, 1.0, offset.x, offset.y)
+    }
+
+    /// Creates scaling by factors along axes
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Creates rotation by angle (in radians, counter-clockwise when Y axis points upward)
+    pub fn rotate(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, -sin, sin, cos, 0.0, 0.0)
+    }
+
+    /// Creates skew by factors
+    ///
+    /// The `kx` shifts points horizontally proportional to their Y coordinate,
+    /// so `skew(0.2, 0.0)` makes oblique glyph from upright one.
+    pub fn skew(kx: f64, ky: f64) -> Self {
+        Self::new(1.0, kx, ky, 1.0, 0.0, 0.0)
+    }
+
+    /// Composes transformation with another one which is applied next
+    pub fn then(self, next: Self) -> Self {
+        next * self
+    }
+
+    /// Gets determinant of linear part
+    ///
+    /// Negative determinant means the transformation mirrors shapes.
+    pub fn determinant(&self) -> f64 {
+        self.xx * self.yy - self.xy * self.yx
+    }
+
+    /// Gets inverse transformation
+    ///
+    /// Returns `None` when transformation is degenerate.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let xx = self.yy / det;
+        let xy = -self.xy / det;
+        let yx = -self.yx / det;
+        let yy = self.xx / det;
+        Some(Self::new(
+            xx,
+            xy,
+            yx,
+            yy,
+            -(xx * self.dx + xy * self.dy),
+            -(yx * self.dx + yy * self.dy),
+        ))
+    }
+
+    /// Transforms point
+    pub fn transform_point(&self, point: Point2<f64>) -> Point2<f64> {
+        self.transform_vector(point) + Vector2::new(self.dx, self.dy)
+    }
+
+    /// Transforms vector (the translation is not applied)
+    pub fn transform_vector(&self, vector: Vector2<f64>) -> Vector2<f64> {
+        Vector2::new(
+            self.xx * vector.x + self.xy * vector.y,
+            self.yx * vector.x + self.yy * vector.y,
+        )
+    }
+}
+
+impl Mul for Affine2 {
+    type Output = Affine2;
+
+    fn mul(self, other: Affine2) -> Self::Output {
+        Affine2::new(
+            self.xx * other.xx + self.xy * other.yx,
+            self.xx * other.xy + self.xy * other.yy,
+            self.yx * other.xx + self.yy * other.yx,
+            self.yx * other.xy + self.yy * other.yy,
+            self.xx * other.dx + self.xy * other.dy + self.dx,
+            self.yx * other.dx + self.yy * other.dy + self.dy,
+        )
+    }
+}
+
+impl MulAssign for Affine2 {
+    fn mul_assign(&mut self, other: Affine2) {
+        *self = *self * other;
+    }
+}
+
+impl Mul<Point2<f64>> for Affine2 {
+    type Output = Point2<f64>;
+
+    fn mul(self, point: Point2<f64>) -> Self::Output {
+        self.transform_point(point)
+    }
+}
+
+impl Contour {
+    /// Transforms all edges of contour
+    ///
+    /// When transformation mirrors the contour its edges are reversed to keep the orientation.
+    pub fn transform(&mut self, affine: &Affine2) {
+        for edge in self.edges_mut() {
+            edge.transform(affine);
+        }
+        if affine.determinant() < 0.0 {
+            self.reverse();
+        }
+    }
+}
+
+impl Shape {
+    /// Transforms all contours of shape
+    ///
+    /// When transformation mirrors the shape the contours are reversed to keep the filled area
+    /// the same, so mirrored glyphs don't become inside out.
+    pub fn transform(&mut self, affine: &Affine2) {
+        for contour in self.contours_mut() {
+            contour.transform(affine);
+        }
+    }
+
+    /// Gets transformed shape
+    ///
+    /// See [`Shape::transform`].
+    pub fn transformed(mut self, affine: &Affine2) -> Self {
+        self.transform(affine);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::f64::consts::FRAC_PI_2;
+
+    fn assert_near(a: Point2<f64>, b: Point2<f64>) {
+        assert!((a - b).length() < 1e-9, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn composition() {
+        let affine = Affine2::scale(2.0, 3.0)
+            .then(Affine2::rotate(FRAC_PI_2))
+            .then(Affine2::translate((1.0, 0.0)));
+
+        assert_near(affine * Point2::new(1.0, 1.0), Point2::new(-2.0, 2.0));
+        assert_near(
+            affine.transform_vector(Vector2::new(1.0, 0.0)),
+            Vector2::new(0.0, 2.0),
+        );
+        assert!((affine.determinant() - 6.0).abs() < 1e-9);
+
+        let inverse = affine.inverse().unwrap();
+        assert_near(inverse * Point2::new(-2.0, 2.0), Point2::new(1.0, 1.0));
+        assert_near(
+            (affine * inverse) * Point2::new(5.0, 7.0),
+            Point2::new(5.0, 7.0),
+        );
+
+        assert!(Affine2::scale(0.0, 1.0).inverse().is_none());
+        assert_eq!(Affine2::default(), Affine2::IDENTITY);
+    }
+
+    #[test]
+    fn shape_transform() {
+        let shape = Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z").unwrap();
+        let winding = shape.contours()[0].winding();
+
+        let skewed = Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z")
+            .unwrap()
+            .transformed(&Affine2::skew(0.5, 0.0));
+        let edges = skewed.contours()[0].edges();
+        assert_eq!(*edges[0].end_point(), Point2::new(5.0, 10.0));
+        assert_eq!(*edges[1].end_point(), Point2::new(15.0, 10.0));
+
+        // mirroring keeps orientation of contours
+        let mut flipped = shape;
+        flipped.transform(&Affine2::scale(1.0, -1.0).then(Affine2::translate((0.0, 10.0))));
+        assert_eq!(flipped.contours()[0].winding(), winding);
+        assert!(flipped.validate());
+
+        assert_eq!(
+            flipped.get_tight_bound(),
+            crate::Bound::new(0.0, 0.0, 10.0, 10.0)
+        );
+    }
+}
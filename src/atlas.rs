@@ -0,0 +1,581 @@
+use crate::{
+    Bitmap, Bound, FieldGeneration, FontMetrics, FontMetricsExt, Framing, MsdfGeneratorConfig,
+    Shape, Vector2,
+};
+use bytemuck::Pod;
+use std::collections::HashSet;
+
+/// Atlas generation config
+#[derive(Clone, Copy)]
+pub struct AtlasConfig {
+    em_size: f64,
+    range: f64,
+    padding: u32,
+    page_width: u32,
+    page_height: u32,
+    angle_threshold: f64,
+    seed: u64,
+    generator: MsdfGeneratorConfig,
+}
+
+impl AsRef<AtlasConfig> for AtlasConfig {
+    fn as_ref(&self) -> &AtlasConfig {
+        self
+    }
+}
+
+impl AsMut<AtlasConfig> for AtlasConfig {
+    fn as_mut(&mut self) -> &mut AtlasConfig {
+        self
+    }
+}
+
+impl Default for AtlasConfig {
+    fn default() -> Self {
+        Self {
+            em_size: 32.0,
+            range: 2.0,
+            padding: 0,
+            page_width: 512,
+            page_height: 512,
+            angle_threshold: 3.0,
+            seed: 0,
+            generator: MsdfGeneratorConfig::default(),
+        }
+    }
+}
+
+impl AtlasConfig {
+    /// Get em size (in pixels)
+    #[inline(always)]
+    pub fn get_em_size(&self) -> f64 {
+        self.em_size
+    }
+
+    /// Set em size (in pixels)
+    #[inline(always)]
+    pub fn set_em_size(&mut self, em_size: f64) {
+        self.em_size = em_size;
+    }
+
+    /// Configure em size (in pixels)
+    #[inline(always)]
+    pub fn with_em_size(mut self, em_size: f64) -> Self {
+        self.set_em_size(em_size);
+        self
+    }
+
+    /// Get distance field range (in pixels)
+    #[inline(always)]
+    pub fn get_range(&self) -> f64 {
+        self.range
+    }
+
+    /// Set distance field range (in pixels)
+    #[inline(always)]
+    pub fn set_range(&mut self, range: f64) {
+        self.range = range;
+    }
+
+    /// Configure distance field range (in pixels)
+    #[inline(always)]
+    pub fn with_range(mut self, range: f64) -> Self {
+        self.set_range(range);
+        self
+    }
+
+    /// Get glyph padding (in pixels)
+    #[inline(always)]
+    pub fn get_padding(&self) -> u32 {
+        self.padding
+    }
+
+    /// Set glyph padding (in pixels)
+    ///
+    /// The padding extends the box of each glyph beyond the distance field range.
+    #[inline(always)]
+    pub fn set_padding(&mut self, padding: u32) {
+        self.padding = padding;
+    }
+
+    /// Configure glyph padding (in pixels)
+    #[inline(always)]
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.set_padding(padding);
+        self
+    }
+
+    /// Get page size (in pixels)
+    #[inline(always)]
+    pub fn get_page_size(&self) -> (u32, u32) {
+        (self.page_width, self.page_height)
+    }
+
+    /// Set page size (in pixels)
+    #[inline(always)]
+    pub fn set_page_size(&mut self, width: u32, height: u32) {
+        self.page_width = width;
+        self.page_height = height;
+    }
+
+    /// Configure page size (in pixels)
+    #[inline(always)]
+    pub fn with_page_size(mut self, width: u32, height: u32) -> Self {
+        self.set_page_size(width, height);
+        self
+    }
+
+    /// Get edge coloring angle threshold (in radians)
+    #[inline(always)]
+    pub fn get_angle_threshold(&self) -> f64 {
+        self.angle_threshold
+    }
+
+    /// Set edge coloring angle threshold (in radians)
+    #[inline(always)]
+    pub fn set_angle_threshold(&mut self, angle_threshold: f64) {
+        self.angle_threshold = angle_threshold;
+    }
+
+    /// Configure edge coloring angle threshold (in radians)
+    #[inline(always)]
+    pub fn with_angle_threshold(mut self, angle_threshold: f64) -> Self {
+        self.set_angle_threshold(angle_threshold);
+        self
+    }
+
+    /// Get edge coloring seed
+    #[inline(always)]
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Set edge coloring seed
+    #[inline(always)]
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Configure edge coloring seed
+    #[inline(always)]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.set_seed(seed);
+        self
+    }
+
+    /// Get generator config
+    #[inline(always)]
+    pub fn get_generator(&self) -> &MsdfGeneratorConfig {
+        &self.generator
+    }
+
+    /// Set generator config
+    #[inline(always)]
+    pub fn set_generator(&mut self, generator: MsdfGeneratorConfig) {
+        self.generator = generator;
+    }
+
+    /// Configure generator config
+    #[inline(always)]
+    pub fn with_generator(mut self, generator: MsdfGeneratorConfig) -> Self {
+        self.set_generator(generator);
+        self
+    }
+}
+
+/// Placement of glyph image in atlas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasPlacement {
+    /// Index of atlas page
+    pub page: usize,
+    /// Bounds of glyph quad relative to pen position on baseline (in ems, y-up)
+    pub plane_bound: Bound<f64>,
+    /// Bounds of glyph image on atlas page (in pixels, y-up)
+    pub atlas_bound: Bound<u32>,
+}
+
+/// Glyph of atlas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasGlyph {
+    /// Character of glyph
+    pub chr: char,
+    /// Horizontal advance (in ems)
+    pub advance: f64,
+    /// Placement of glyph image (`None` for glyphs without outline like space)
+    pub placement: Option<AtlasPlacement>,
+}
+
+/// Kerning between pair of atlas glyphs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasKerning {
+    /// Character of left glyph
+    pub left: char,
+    /// Character of right glyph
+    pub right: char,
+    /// Horizontal advance adjustment (in ems)
+    pub advance: f64,
+}
+
+/// Font atlas with distance fields of glyphs packed into pages
+pub struct Atlas<T> {
+    /// Em size (in pixels)
+    pub em_size: f64,
+    /// Distance field range (in pixels)
+    pub range: f64,
+    /// Font metrics (in ems)
+    pub metrics: FontMetrics,
+    /// Atlas pages
+    pub pages: Vec<Bitmap<T>>,
+    /// Atlas glyphs in order of characters
+    pub glyphs: Vec<AtlasGlyph>,
+    /// Non-zero kerning between atlas glyphs
+    ///
+    /// Kerning comes from the legacy `kern` table only (see [`FontMetricsExt::glyph_kerning`]),
+    /// so it is empty for fonts which have pair adjustments in `GPOS` table only.
+    pub kerning: Vec<AtlasKerning>,
+}
+
+impl<T: FieldGeneration + Pod> Atlas<T> {
+    /// Generates atlas for characters of font
+    ///
+    /// Characters which are missing in font are skipped.
+    /// Glyphs are packed into pages of fixed size so the output depends on inputs only.
+    ///
+    /// Returns `None` when some glyph does not fit into a page.
+    pub fn generate<F>(
+        font: &F,
+        chars: impl IntoIterator<Item = char>,
+        config: impl AsRef<AtlasConfig>,
+    ) -> Option<Self>
+    where
+        F: FontMetricsExt,
+        F::Glyph: Copy,
+    {
+        let config = config.as_ref();
+        let units_per_em = font.units_per_em();
+
+        let mut glyphs = Vec::new();
+        let mut glyph_ids = Vec::new();
+        let mut images = Vec::new();
+        let mut seen = HashSet::new();
+
+        for chr in chars {
+            if !seen.insert(chr) {
+                continue;
+            }
+
+            let glyph = if let Some(glyph) = font.glyph_index(chr) {
+                glyph
+            } else {
+                continue;
+            };
+
+            let advance = font.glyph_advance(glyph).unwrap_or_default() / units_per_em;
+
+            if let Some(image) = font
+                .glyph_shape(glyph)
+                .and_then(|shape| GlyphImage::new(shape, units_per_em, config))
+            {
+                images.push((glyphs.len(), image));
+            }
+
+            glyphs.push(AtlasGlyph {
+                chr,
+                advance,
+                placement: None,
+            });
+            glyph_ids.push(glyph);
+        }
+
+        let kerning = font
+            .glyphs_kerning(&glyph_ids)
+            .into_iter()
+            .map(|(left, right, advance)| AtlasKerning {
+                left: glyphs[left].chr,
+                right: glyphs[right].chr,
+                advance: advance / units_per_em,
+            })
+            .collect();
+
+        let sizes = images
+            .iter()
+            .map(|(_, image)| (image.width, image.height))
+            .collect::<Vec<_>>();
+
+        let places = pack(&sizes, config.page_width, config.page_height)?;
+
+        let mut pages = Vec::new();
+
+        for ((index, image), (page, x, y)) in images.into_iter().zip(places) {
+            while pages.len() <= page {
+                pages.push(Bitmap::new(config.page_width, config.page_height));
+            }
+
+            pages[page].copy_from(&image.generate::<T>(config), x, y);
+
+            glyphs[index].placement = Some(AtlasPlacement {
+                page,
+                plane_bound: image.plane_bound,
+                atlas_bound: Bound::new(x, y, x + image.width, y + image.height),
+            });
+        }
+
+        Some(Self {
+            em_size: config.em_size,
+            range: config.range,
+            metrics: font.font_metrics().scaled(1.0 / units_per_em),
+            pages,
+            glyphs,
+            kerning,
+        })
+    }
+}
+
+impl<T> Atlas<T> {
+    /// Finds glyph of specific character
+    pub fn glyph(&self, chr: char) -> Option<&AtlasGlyph> {
+        self.glyphs.iter().find(|glyph| glyph.chr == chr)
+    }
+}
+
+/// Prepared glyph shape with its box in atlas
+pub(crate) struct GlyphImage {
+    pub shape: Shape,
+    pub framing: Framing<f64>,
+    /// Box of glyph image relative to pen position on baseline (in ems)
+    pub plane_bound: Bound<f64>,
+    /// Size of glyph image (in pixels)
+    pub width: u32,
+    pub height: u32,
+}
+
+impl GlyphImage {
+    /// Computes the box and framing of glyph and prepares its shape for generation
+    ///
+    /// Returns `None` for empty shapes.
+    pub fn new(mut shape: Shape, units_per_em: f64, config: &AtlasConfig) -> Option<Self> {
+        let scale = config.em_size / units_per_em;
+        let padding = config.padding as f64;
+
+        let bound = shape.get_tight_bound();
+
+        if bound.left >= bound.right || bound.bottom >= bound.top {
+            return None;
+        }
+
+        // glyph box in pixels relative to origin
+        let mut left = bound.left * scale - 0.5 * config.range - padding;
+        let mut bottom = bound.bottom * scale - 0.5 * config.range - padding;
+        let right = bound.right * scale + 0.5 * config.range + padding;
+        let top = bound.top * scale + 0.5 * config.range + padding;
+
+        let width = (right - left).ceil();
+        let height = (top - bottom).ceil();
+
+        left -= 0.5 * (width - (right - left));
+        bottom -= 0.5 * (height - (top - bottom));
+
+        let framing = Framing::new(
+            config.range / scale,
+            scale,
+            Vector2::new(-left, -bottom) / scale,
+        );
+
+        let plane_bound = Bound::new(
+            left / config.em_size,
+            bottom / config.em_size,
+            (left + width) / config.em_size,
+            (bottom + height) / config.em_size,
+        );
+
+        shape.normalize();
+        shape.edge_coloring_simple(config.angle_threshold, config.seed);
+
+        Some(Self {
+            shape,
+            framing,
+            plane_bound,
+            width: width as u32,
+            height: height as u32,
+        })
+    }
+
+    /// Generates distance field of glyph
+    pub fn generate<T: FieldGeneration + Pod>(&self, config: &AtlasConfig) -> Bitmap<T> {
+        let mut bitmap = Bitmap::new(self.width, self.height);
+        self.shape
+            .generate_field(&mut bitmap, self.framing, config.generator);
+        bitmap
+    }
+}
+
+/// Packs rectangles into pages using shelf algorithm
+///
+/// Returns page, x and y for each rectangle or `None` when some rectangle does not fit into page.
+fn pack(sizes: &[(u32, u32)], page_width: u32, page_height: u32) -> Option<Vec<(usize, u32, u32)>> {
+    struct Shelf {
+        y: u32,
+        height: u32,
+        x: u32,
+    }
+
+    struct Page {
+        shelves: Vec<Shelf>,
+        top: u32,
+    }
+
+    // pack tallest rectangles first to reduce wasted space of shelves
+    let mut order = (0..sizes.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| {
+        let (a_width, a_height) = sizes[a];
+        let (b_width, b_height) = sizes[b];
+        b_height
+            .cmp(&a_height)
+            .then(b_width.cmp(&a_width))
+            .then(a.cmp(&b))
+    });
+
+    let mut pages: Vec<Page> = Vec::new();
+    let mut places = vec![(0, 0, 0); sizes.len()];
+
+    for index in order {
+        let (width, height) = sizes[index];
+
+        if width > page_width || height > page_height {
+            return None;
+        }
+
+        let place = pages.iter_mut().enumerate().find_map(|(page_index, page)| {
+            if let Some(shelf) = page
+                .shelves
+                .iter_mut()
+                .find(|shelf| height <= shelf.height && shelf.x + width <= page_width)
+            {
+                let place = (page_index, shelf.x, shelf.y);
+                shelf.x += width;
+                return Some(place);
+            }
+
+            if page.top + height <= page_height {
+                let place = (page_index, 0, page.top);
+                page.shelves.push(Shelf {
+                    y: page.top,
+                    height,
+                    x: width,
+                });
+                page.top += height;
+                return Some(place);
+            }
+
+            None
+        });
+
+        places[index] = if let Some(place) = place {
+            place
+        } else {
+            pages.push(Page {
+                shelves: vec![Shelf {
+                    y: 0,
+                    height,
+                    x: width,
+                }],
+                top: height,
+            });
+            (pages.len() - 1, 0, 0)
+        };
+    }
+
+    Some(places)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn overlaps(a: (usize, u32, u32, u32, u32), b: (usize, u32, u32, u32, u32)) -> bool {
+        a.0 == b.0 && a.1 < b.1 + b.3 && b.1 < a.1 + a.3 && a.2 < b.2 + b.4 && b.2 < a.2 + a.4
+    }
+
+    #[test]
+    fn pack_shelves() {
+        let sizes = [(10, 8), (6, 12), (10, 8), (20, 4), (7, 7), (3, 12)];
+        let places = pack(&sizes, 24, 24).unwrap();
+
+        let rects = places
+            .iter()
+            .zip(sizes.iter())
+            .map(|(&(page, x, y), &(width, height))| (page, x, y, width, height))
+            .collect::<Vec<_>>();
+
+        for (index, rect) in rects.iter().enumerate() {
+            assert!(rect.1 + rect.3 <= 24 && rect.2 + rect.4 <= 24);
+            for other in &rects[index + 1..] {
+                assert!(!overlaps(*rect, *other));
+            }
+        }
+
+        assert_eq!(places, pack(&sizes, 24, 24).unwrap());
+    }
+
+    #[test]
+    fn pack_pages() {
+        let places = pack(&[(16, 16); 5], 32, 32).unwrap();
+
+        assert_eq!(places.iter().filter(|(page, _, _)| *page == 0).count(), 4);
+        assert_eq!(places[4], (1, 0, 0));
+        assert!(pack(&[(33, 1)], 32, 32).is_none());
+    }
+
+    #[cfg(feature = "ttf-parser")]
+    #[test]
+    fn generate_atlas() {
+        use crate::Rgb;
+        use notosans::REGULAR_TTF;
+        use ttf_parser::Face;
+
+        let font = Face::parse(REGULAR_TTF, 0).unwrap();
+        let config = AtlasConfig::default()
+            .with_em_size(24.0)
+            .with_range(4.0)
+            .with_page_size(128, 128);
+
+        let atlas = Atlas::<Rgb<f32>>::generate(&font, "Hello, world!".chars(), config).unwrap();
+
+        assert_eq!(atlas.glyphs.len(), 10);
+
+        let space = atlas.glyph(' ').unwrap();
+        assert!(space.placement.is_none());
+        assert!(space.advance > 0.0);
+
+        assert!(atlas.metrics.ascender > 0.0 && atlas.metrics.descender < 0.0);
+
+        let letter = atlas.glyph('H').unwrap().placement.unwrap();
+        let size = (letter.atlas_bound.right - letter.atlas_bound.left) as f64;
+        assert!((letter.plane_bound.width() * atlas.em_size - size).abs() < 1e-9);
+
+        let again = Atlas::<Rgb<f32>>::generate(&font, "Hello, world!".chars(), config).unwrap();
+        assert_eq!(atlas.glyphs, again.glyphs);
+        assert_eq!(atlas.pages[0].pixels(), again.pages[0].pixels());
+    }
+
+    #[cfg(feature = "ttf-parser")]
+    #[test]
+    fn golden_metadata() {
+        use crate::Rgb;
+        use notosans::REGULAR_TTF;
+        use ttf_parser::Face;
+
+        let font = Face::parse(REGULAR_TTF, 0).unwrap();
+        let config = AtlasConfig::default()
+            .with_em_size(16.0)
+            .with_range(2.0)
+            .with_page_size(64, 64);
+
+        // placement and metadata must not change between runs and platforms
+        let atlas = Atlas::<Rgb<f32>>::generate(&font, "AVo.,".chars(), config).unwrap();
+        assert_eq!(
+            atlas.to_json(),
+            include_str!("../tests/fixtures/atlas.json").trim_end()
+        );
+    }
+}
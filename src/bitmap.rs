@@ -33,6 +33,7 @@ pub struct Bitmap<T> {
 }
 
 unsafe impl<T: Send> Send for Bitmap<T> {}
+unsafe impl<T: Sync> Sync for Bitmap<T> {}
 
 impl<T> AsRef<Bitmap<T>> for Bitmap<T> {
     fn as_ref(&self) -> &Bitmap<T> {
@@ -190,6 +191,28 @@ impl<T> Bitmap<T> {
         }
     }
 
+    /// Copy pixels of other bitmap to specified position
+    ///
+    /// Pixels which do not fit into bitmap are skipped.
+    pub fn copy_from(&mut self, source: &Bitmap<T>, x: u32, y: u32)
+    where
+        T: Copy,
+    {
+        if x >= self.width() || y >= self.height() {
+            return;
+        }
+
+        let width = source.width().min(self.width() - x) as usize;
+        let height = source.height().min(self.height() - y);
+
+        for row in 0..height {
+            let offset = (x + (y + row) * self.width()) as usize;
+            let source_offset = (row * source.width()) as usize;
+            self.pixels_mut()[offset..offset + width]
+                .copy_from_slice(&source.pixels()[source_offset..source_offset + width]);
+        }
+    }
+
     /// Convert bitmap data type
     pub fn convert<R>(&self) -> Bitmap<R>
     where
@@ -0,0 +1,380 @@
+use crate::{
+    geometry::{contour_curves, intersect, scanline_sum, Bezier},
+    FillRule, Point2, Shape, ShapeBuilder,
+};
+
+/// Boolean operation on filled areas of shapes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanOp {
+    /// Area which is filled in any of shapes
+    Union,
+    /// Area which is filled in both shapes
+    Intersection,
+    /// Area which is filled in first shape but not in second one
+    Difference,
+    /// Area which is filled in exactly one of shapes
+    Xor,
+}
+
+impl BooleanOp {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::Union => a || b,
+            Self::Intersection => a && b,
+            Self::Difference => a && !b,
+            Self::Xor => a != b,
+        }
+    }
+}
+
+impl Shape {
+    /// Combines filled areas of shapes using boolean operation
+    ///
+    /// The edges of both shapes are split at intersections and only the parts which bound
+    /// the resulting area are kept, so the result has no overlapping contours and can be generated
+    /// without overlap support. The filled area of each shape is determined by fill rule.
+    ///
+    /// Resulting contours are oriented so that the filled area is on the right side of edges
+    /// (when Y axis points upward). Edge colors are not preserved so edge coloring should be applied
+    /// to the result.
+    pub fn boolean(&self, other: &Shape, op: BooleanOp, fill_rule: FillRule) -> Shape {
+        let curves = |shape: &Shape| -> Vec<_> {
+            shape.contours().iter().flat_map(contour_curves).collect()
+        };
+        let (a, b) = (curves(self), curves(other));
+        let filled = |curves: &[Bezier], point| fill_rule.filled(scanline_sum(curves, point));
+
+        let mut shape = combine(a.iter().chain(&b).copied().collect(), |point| {
+            op.apply(filled(&a, point), filled(&b, point))
+        });
+
+        shape.set_inverse_y_axis(self.get_inverse_y_axis());
+        shape
+    }
+
+    /// Gets union of shapes using non-zero fill rule
+    ///
+    /// See [`Shape::boolean`].
+    pub fn union(&self, other: &Shape) -> Shape {
+        self.boolean(other, BooleanOp::Union, FillRule::NonZero)
+    }
+
+    /// Gets intersection of shapes using non-zero fill rule
+    ///
+    /// See [`Shape::boolean`].
+    pub fn intersection(&self, other: &Shape) -> Shape {
+        self.boolean(other, BooleanOp::Intersection, FillRule::NonZero)
+    }
+
+    /// Gets difference of shapes using non-zero fill rule
+    ///
+    /// See [`Shape::boolean`].
+    pub fn difference(&self, other: &Shape) -> Shape {
+        self.boolean(other, BooleanOp::Difference, FillRule::NonZero)
+    }
+
+    /// Gets symmetric difference of shapes using non-zero fill rule
+    ///
+    /// See [`Shape::boolean`].
+    pub fn xor(&self, other: &Shape) -> Shape {
+        self.boolean(other, BooleanOp::Xor, FillRule::NonZero)
+    }
+
+    /// Gets equivalent shape without overlapping and self-intersecting contours
+    ///
+    /// This is useful for fonts with overlapping contours (like variable fonts)
+    /// which otherwise require generation with overlap support.
+    /// See [`Shape::boolean`].
+    pub fn resolve_overlaps(&self, fill_rule: FillRule) -> Shape {
+        self.boolean(&Shape::default(), BooleanOp::Union, fill_rule)
+    }
+}
+
+/// Builds shape from curves which bound the area where `inside` is true
+fn combine(curves: Vec<Bezier>, inside: impl Fn(Point2<f64>) -> bool) -> Shape {
+    let (min, max) = curves
+        .iter()
+        .map(Bezier::control_bound)
+        .reduce(|(min_a, max_a), (min_b, max_b)| {
+            (
+                Point2::new(min_a.x.min(min_b.x), min_a.y.min(min_b.y)),
+                Point2::new(max_a.x.max(max_b.x), max_a.y.max(max_b.y)),
+            )
+        })
+        .unwrap_or_default();
+
+    let size = (max - min).length();
+    if size == 0.0 {
+        return Shape::default();
+    }
+
+    let tolerance = size * 1e-9;
+    let snap = size * 1e-7;
+    let offset = size * 1e-5;
+
+    let mut splits = vec![Vec::new(); curves.len()];
+    for j in 1..curves.len() {
+        let (head, tail) = splits.split_at_mut(j);
+        for i in 0..j {
+            intersect(
+                &curves[i],
+                &curves[j],
+                tolerance,
+                &mut head[i],
+                &mut tail[0],
+            );
+        }
+    }
+
+    let mut vertices = Vertices::new(snap);
+    let mut edges: Vec<Edge> = Vec::new();
+
+    for (curve, splits) in curves.iter().zip(splits) {
+        for mut piece in split_curve(curve, splits, snap) {
+            let start = vertices.find(piece.start());
+            let end = vertices.find(piece.end());
+            piece.set_ends(vertices.points[start], vertices.points[end]);
+
+            let (min, max) = piece.control_bound();
+            if start == end && (max - min).length() <= snap {
+                continue;
+            }
+
+            let middle = piece.point(0.5);
+            let normal = piece.direction(0.5).get_orthonormal(true, false) * offset;
+            let (left, right) = (inside(middle + normal), inside(middle - normal));
+
+            // filled area should be on the right side
+            let edge = match (left, right) {
+                (false, true) => Edge {
+                    start,
+                    end,
+                    curve: piece,
+                },
+                (true, false) => Edge {
+                    start: end,
+                    end: start,
+                    curve: piece.reversed(),
+                },
+                _ => continue,
+            };
+
+            // coinciding edges of different contours
+            if !edges.iter().any(|other| {
+                other.start == edge.start
+                    && other.end == edge.end
+                    && (other.curve.point(0.5) - middle).length() <= snap
+            }) {
+                edges.push(edge);
+            }
+        }
+    }
+
+    let mut outgoing = vec![Vec::new(); vertices.points.len()];
+    for (index, edge) in edges.iter().enumerate() {
+        outgoing[edge.start].push(index);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut builder = ShapeBuilder::new();
+
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+
+        used[first] = true;
+        builder.move_to(edges[first].curve.start());
+        edges[first].curve.add_to(&mut builder);
+
+        let mut current = first;
+        while edges[current].end != edges[first].start {
+            let direction = edges[current].curve.end_direction();
+
+            // take the rightmost turn at junctions
+            let next = outgoing[edges[current].end]
+                .iter()
+                .copied()
+                .filter(|index| !used[*index])
+                .map(|index| {
+                    let next_direction = edges[index].curve.start_direction();
+                    let turn = direction
+                        .cross(&next_direction)
+                        .atan2(direction.dot(&next_direction));
+                    (index, turn)
+                })
+                .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+            if let Some((next, _)) = next {
+                used[next] = true;
+                edges[next].curve.add_to(&mut builder);
+                current = next;
+            } else {
+                break;
+            }
+        }
+
+        builder.close();
+    }
+
+    builder.build()
+}
+
+/// Boundary edge between snapped vertices
+struct Edge {
+    start: usize,
+    end: usize,
+    curve: Bezier,
+}
+
+/// Set of points where close points are merged
+struct Vertices {
+    points: Vec<Point2<f64>>,
+    snap: f64,
+}
+
+impl Vertices {
+    fn new(snap: f64) -> Self {
+        Self {
+            points: Vec::new(),
+            snap,
+        }
+    }
+
+    /// Gets index of vertex which is close to point
+    fn find(&mut self, point: Point2<f64>) -> usize {
+        self.points
+            .iter()
+            .position(|vertex| (*vertex - point).length() <= self.snap)
+            .unwrap_or_else(|| {
+                self.points.push(point);
+                self.points.len() - 1
+            })
+    }
+}
+
+/// Splits curve at parameters skipping the parts which are shorter than snap distance
+fn split_curve(curve: &Bezier, mut params: Vec<f64>, snap: f64) -> Vec<Bezier> {
+    params.retain(|t| *t > 0.0 && *t < 1.0);
+    params.sort_by(f64::total_cmp);
+
+    let mut pieces = Vec::new();
+    let mut start = (0.0, curve.start());
+
+    for t in params {
+        let point = curve.point(t);
+        if (point - start.1).length() <= snap || (curve.end() - point).length() <= snap {
+            continue;
+        }
+        pieces.push(curve.segment(start.0, t));
+        start = (t, point);
+    }
+
+    pieces.push(curve.segment(start.0, 1.0));
+    pieces
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{shape::test::square, Bound};
+
+    fn filled(shape: &Shape, x: f64, y: f64) -> bool {
+        shape.scanline(y).filled(x, FillRule::NonZero)
+    }
+
+    #[test]
+    fn operations() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(5.0, 5.0, 10.0);
+        let winding = a.contours()[0].winding();
+
+        let union = a.union(&b);
+        assert_eq!(union.contours().len(), 1);
+        assert_eq!(union.contours()[0].edges().len(), 8);
+        assert_eq!(union.contours()[0].winding(), winding);
+        assert!(union.validate());
+        assert_eq!(union.get_tight_bound(), Bound::new(0.0, 0.0, 15.0, 15.0));
+        assert!(filled(&union, 2.0, 2.0) && filled(&union, 12.0, 12.0));
+        assert!(!filled(&union, 12.0, 2.0) && !filled(&union, 2.0, 12.0));
+
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.contours().len(), 1);
+        assert_eq!(intersection.contours()[0].edges().len(), 4);
+        assert_eq!(
+            intersection.get_tight_bound(),
+            Bound::new(5.0, 5.0, 10.0, 10.0)
+        );
+
+        let difference = a.difference(&b);
+        assert_eq!(difference.contours().len(), 1);
+        assert_eq!(difference.contours()[0].edges().len(), 6);
+        assert!(filled(&difference, 2.0, 2.0) && !filled(&difference, 7.0, 7.0));
+
+        let xor = a.xor(&b);
+        assert!(xor.validate());
+        assert!(filled(&xor, 2.0, 2.0) && filled(&xor, 12.0, 12.0));
+        assert!(!filled(&xor, 7.0, 7.0) && !filled(&xor, 12.0, 2.0));
+
+        // disjoint shapes
+        assert!(a
+            .intersection(&square(20.0, 0.0, 5.0))
+            .contours()
+            .is_empty());
+        assert_eq!(a.union(&square(20.0, 0.0, 5.0)).contours().len(), 2);
+    }
+
+    #[test]
+    fn overlaps() {
+        let shape = Shape::from_svg_path_data(
+            "M0 0 v10 h10 v-10 Z M5 5 v10 h10 v-10 Z M10 0 v10 h10 v-10 Z",
+        )
+        .unwrap();
+
+        let resolved = shape.resolve_overlaps(FillRule::NonZero);
+        assert_eq!(resolved.contours().len(), 1);
+        assert!(resolved.validate());
+        assert_eq!(resolved.get_tight_bound(), Bound::new(0.0, 0.0, 20.0, 15.0));
+        assert!(filled(&resolved, 10.0, 2.0) && filled(&resolved, 12.0, 12.0));
+        assert!(!filled(&resolved, 2.0, 12.0) && !filled(&resolved, 17.0, 12.0));
+
+        let resolved = shape.resolve_overlaps(FillRule::Odd);
+        assert!(!filled(&resolved, 7.0, 7.0) && filled(&resolved, 2.0, 2.0));
+
+        // self-intersecting contour
+        let bowtie = Shape::from_svg_path_data("M0 0 L10 10 L10 0 L0 10 Z").unwrap();
+        let resolved = bowtie.resolve_overlaps(FillRule::NonZero);
+        assert!(resolved
+            .contours()
+            .iter()
+            .all(|contour| contour.winding() == resolved.contours()[0].winding()));
+        assert!(filled(&resolved, 1.0, 5.0) && filled(&resolved, 9.0, 5.0));
+        assert!(!filled(&resolved, 5.0, 1.0));
+    }
+
+    #[test]
+    fn curves() {
+        let circle = |x: f64| {
+            Shape::from_svg_path_data(&format!(
+                "M{} 0 A10 10 0 0 0 {} 0 A10 10 0 0 0 {} 0 Z",
+                x - 10.0,
+                x + 10.0,
+                x - 10.0
+            ))
+            .unwrap()
+        };
+
+        let union = circle(0.0).union(&circle(10.0));
+        assert_eq!(union.contours().len(), 1);
+        assert!(union.validate());
+
+        let bound = union.get_tight_bound();
+        assert!((bound.left + 10.0).abs() < 1e-6 && (bound.right - 20.0).abs() < 1e-6);
+        assert!(filled(&union, 5.0, 8.5) && !filled(&union, 5.0, 8.8));
+
+        let intersection = circle(0.0).intersection(&circle(10.0));
+        assert_eq!(intersection.contours().len(), 1);
+        let bound = intersection.get_tight_bound();
+        assert!((bound.left).abs() < 1e-6 && (bound.right - 10.0).abs() < 1e-6);
+    }
+}
@@ -3,6 +3,7 @@ use core::ops::{Neg, Sub};
 
 /// Range specifier
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Range<T> {
     Unit(T),
     Px(T),
@@ -37,6 +38,7 @@ where
 
 /// Bounding box of shape or contour
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Bound<T> {
     pub left: T,
@@ -82,6 +84,16 @@ impl<T> Bound<T> {
 }
 
 impl Bound<f64> {
+    /// Creates empty bounding box which is extended by the first fitted point
+    pub fn empty() -> Self {
+        Self::new(f64::MAX, f64::MAX, f64::MIN, f64::MIN)
+    }
+
+    /// Checks that bounding box contains no points
+    pub fn is_empty(&self) -> bool {
+        self.left > self.right || self.bottom > self.top
+    }
+
     /// Autoframing
     ///
     /// Returns `None` means that frame cannot fit the specified pixel range.
@@ -0,0 +1,235 @@
+use crate::{Contour, EdgeColor, EdgeHolder, Point2, Shape};
+
+/// Builder of shapes using path-style commands
+///
+/// ```
+/// # use msdfgen::ShapeBuilder;
+/// let mut builder = ShapeBuilder::new();
+/// builder
+///     .move_to((0.0, 0.0))
+///     .line_to((0.0, 10.0))
+///     .quad_to((5.0, 15.0), (10.0, 10.0))
+///     .line_to((10.0, 0.0))
+///     .close();
+/// let shape = builder.build();
+/// assert_eq!(shape.contours()[0].edges().len(), 4);
+/// ```
+///
+/// The builder is forgiving like path renderers:
+///
+/// - drawing without preceding `move_to` starts new contour at current point
+///   (the origin initially or the start of last closed contour),
+/// - zero-length edges are skipped,
+/// - opened contour is closed automatically by `move_to` and `build`,
+/// - contours without edges are dropped.
+#[derive(Default)]
+pub struct ShapeBuilder {
+    shape: Shape,
+    contour: Option<Contour>,
+    start_point: Point2<f64>,
+    point: Point2<f64>,
+}
+
+impl ShapeBuilder {
+    /// Creates new builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts new contour at point
+    ///
+    /// Currently opened contour is closed.
+    pub fn move_to(&mut self, point: impl Into<Point2<f64>>) -> &mut Self {
+        self.close();
+        let point = point.into();
+        self.contour = Some(Contour::default());
+        self.start_point = point;
+        self.point = point;
+        self
+    }
+
+    /// Adds linear edge to point
+    pub fn line_to(&mut self, point: impl Into<Point2<f64>>) -> &mut Self {
+        let point = point.into();
+        if point != self.point {
+            let edge = EdgeHolder::new_linear(self.point, point, EdgeColor::default());
+            self.contour().add_edge(&edge);
+            self.point = point;
+        }
+        self
+    }
+
+    /// Adds quadratic edge with control point to point
+    pub fn quad_to(
+        &mut self,
+        cpoint: impl Into<Point2<f64>>,
+        point: impl Into<Point2<f64>>,
+    ) -> &mut Self {
+        let cpoint = cpoint.into();
+        let point = point.into();
+        if cpoint != self.point || point != self.point {
+            let edge = EdgeHolder::new_quadratic(self.point, cpoint, point, EdgeColor::default());
+            self.contour().add_edge(&edge);
+            self.point = point;
+        }
+        self
+    }
+
+    /// Adds cubic edge with control points to point
+    pub fn curve_to(
+        &mut self,
+        c1point: impl Into<Point2<f64>>,
+        c2point: impl Into<Point2<f64>>,
+        point: impl Into<Point2<f64>>,
+    ) -> &mut Self {
+        let c1point = c1point.into();
+        let c2point = c2point.into();
+        let point = point.into();
+        if c1point != self.point || c2point != self.point || point != self.point {
+            let edge =
+                EdgeHolder::new_cubic(self.point, c1point, c2point, point, EdgeColor::default());
+            self.contour().add_edge(&edge);
+            self.point = point;
+        }
+        self
+    }
+
+    /// Closes opened contour
+    ///
+    /// When current point differs from the start of contour the closing linear edge is added,
+    /// so the corner at the end of last edge is preserved.
+    /// The start of contour becomes current point.
+    pub fn close(&mut self) -> &mut Self {
+        if let Some(mut contour) = self.contour.take() {
+            if !contour.edges().is_empty() {
+                if self.point != self.start_point {
+                    contour.add_edge(&EdgeHolder::new_linear(
+                        self.point,
+                        self.start_point,
+                        EdgeColor::default(),
+                    ));
+                }
+                self.shape.add_contour(&contour);
+            }
+            self.point = self.start_point;
+        }
+        self
+    }
+
+    /// Closes opened contour by moving the end of last edge to the start of contour
+    ///
+    /// Unlike [`ShapeBuilder::close`] no closing edge is added, which is useful when
+    /// current point differs from the start only due to rounding errors.
+    /// The closing edge is still added when contour has single edge.
+    pub fn close_snapped(&mut self) -> &mut Self {
+        if let Some(contour) = &mut self.contour {
+            if contour.edges().len() > 1 {
+                if let Some(edge) = contour.edges_mut().last_mut() {
+                    *edge.end_point_mut() = self.start_point;
+                }
+                self.point = self.start_point;
+            }
+        }
+        self.close()
+    }
+
+    /// Closes opened contour and gets resulting shape
+    pub fn build(mut self) -> Shape {
+        self.close();
+        self.shape
+    }
+
+    fn contour(&mut self) -> &mut Contour {
+        if self.contour.is_none() {
+            self.start_point = self.point;
+        }
+        self.contour.get_or_insert_with(Contour::default)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SegmentKind;
+
+    #[test]
+    fn implicit_commands() {
+        let mut builder = ShapeBuilder::new();
+        builder
+            .line_to((10.0, 0.0))
+            .line_to((10.0, 10.0))
+            .move_to((20.0, 0.0))
+            .move_to((30.0, 0.0))
+            .line_to((40.0, 0.0))
+            .line_to((40.0, 10.0));
+        let shape = builder.build();
+
+        // contours are closed automatically and empty contour is dropped
+        assert_eq!(shape.contours().len(), 2);
+        assert!(shape.validate());
+
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(*edges[0].start_point(), Point2::new(0.0, 0.0));
+        assert_eq!(*edges[2].end_point(), Point2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn degenerate_edges() {
+        let mut builder = ShapeBuilder::new();
+        builder
+            .move_to((0.0, 0.0))
+            .line_to((0.0, 0.0))
+            .line_to((10.0, 0.0))
+            .quad_to((10.0, 0.0), (10.0, 0.0))
+            .curve_to((10.0, 0.0), (10.0, 0.0), (10.0, 0.0))
+            .quad_to((10.0, 10.0), (0.0, 10.0))
+            .line_to((0.0, 0.0))
+            .close()
+            // drawing after close starts from the start of closed contour
+            .line_to((-10.0, 0.0))
+            .line_to((-10.0, -10.0));
+        let shape = builder.build();
+
+        assert_eq!(shape.contours().len(), 2);
+
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges[1].segment_kind(), SegmentKind::Quadratic);
+
+        let edges = shape.contours()[1].edges();
+        assert_eq!(*edges[0].start_point(), Point2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn closing() {
+        let mut builder = ShapeBuilder::new();
+        builder
+            .move_to((0.0, 0.0))
+            .line_to((10.0, 0.0))
+            .line_to((10.0, 10.0))
+            .line_to((0.0, 1e-9))
+            .close()
+            .move_to((0.0, 0.0))
+            .line_to((10.0, 0.0))
+            .line_to((10.0, 10.0))
+            .line_to((0.0, 1e-9))
+            .close_snapped()
+            .move_to((0.0, 0.0))
+            .line_to((10.0, 0.0))
+            .close_snapped();
+        let shape = builder.build();
+
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 4);
+        assert_eq!(*edges[3].start_point(), Point2::new(0.0, 1e-9));
+
+        let edges = shape.contours()[1].edges();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(*edges[2].end_point(), Point2::new(0.0, 0.0));
+
+        let edges = shape.contours()[2].edges();
+        assert_eq!(edges.len(), 2);
+        assert!(shape.validate());
+    }
+}
@@ -0,0 +1,579 @@
+use crate::FontMetricsExt;
+use core::{fmt, ops::RangeInclusive, str::FromStr};
+
+const MAX_CODEPOINT: u32 = char::MAX as u32;
+
+/// Set of characters
+///
+/// Characters are stored as sorted non-overlapping ranges of codepoints.
+///
+/// The set can be parsed from msdf-atlas-gen charset syntax, where items are separated by commas:
+///
+/// - `'A'` — single character
+/// - `"abc"` — all characters of string
+/// - `65` or `0x41` — single codepoint
+/// - `[0x20, 0x7e]` or `['a', 'z']` — inclusive range of codepoints
+///
+/// Characters in quotes can be escaped using backslash (`'\''`, `"\""`, `'\\'`, `'\n'`, `'\t'`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Charset {
+    ranges: Vec<(u32, u32)>,
+}
+
+/// Error of charset parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharsetError {
+    /// Byte offset in source where error occurred
+    pub position: usize,
+    /// Error description
+    pub message: &'static str,
+}
+
+impl fmt::Display for CharsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for CharsetError {}
+
+/// Charset resolved to font glyphs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharsetGlyphs<G> {
+    /// Characters with corresponding glyphs
+    pub glyphs: Vec<(char, G)>,
+    /// Characters which are not covered by font
+    pub missing: Charset,
+}
+
+impl Charset {
+    /// Create empty charset
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create charset of printable ASCII characters
+    pub fn ascii() -> Self {
+        Self {
+            ranges: vec![(0x20, 0x7e)],
+        }
+    }
+
+    /// Parse charset from msdf-atlas-gen charset syntax
+    pub fn parse(source: &str) -> Result<Self, CharsetError> {
+        Parser::new(source).parse()
+    }
+
+    /// Check that charset has no characters
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get number of characters
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| (end - start + 1 - surrogates(start, end)) as usize)
+            .sum()
+    }
+
+    /// Check that charset contains character
+    pub fn contains(&self, chr: char) -> bool {
+        let code = chr as u32;
+        self.ranges
+            .binary_search_by(|&(start, end)| {
+                if end < code {
+                    core::cmp::Ordering::Less
+                } else if start > code {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Add character
+    pub fn insert(&mut self, chr: char) {
+        self.insert_codepoints(chr as u32, chr as u32);
+    }
+
+    /// Add inclusive range of characters
+    pub fn insert_range(&mut self, range: RangeInclusive<char>) {
+        let (start, end) = range.into_inner();
+        if start <= end {
+            self.insert_codepoints(start as u32, end as u32);
+        }
+    }
+
+    /// Add all characters of string
+    pub fn insert_str(&mut self, string: &str) {
+        self.extend(string.chars());
+    }
+
+    /// Get inclusive ranges of codepoints
+    ///
+    /// Ranges are sorted and never overlap or touch each other.
+    /// Note that ranges can include surrogate codepoints which are not characters.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<u32>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// Iterate over characters in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = char> + '_ {
+        self.ranges
+            .iter()
+            .flat_map(|&(start, end)| (start..=end).filter_map(char::from_u32))
+    }
+
+    /// Get characters which belongs to either charset
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges = self.ranges.clone();
+        ranges.extend_from_slice(&other.ranges);
+        Self::from_ranges(ranges)
+    }
+
+    /// Get characters which belongs to both charsets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (self.ranges.iter(), other.ranges.iter());
+        let (mut a_range, mut b_range) = (a.next(), b.next());
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) = (a_range, b_range) {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                a_range = a.next();
+            } else {
+                b_range = b.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Get characters which belongs to this charset but not to other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+
+        for &(mut start, end) in &self.ranges {
+            for &(other_start, other_end) in &other.ranges {
+                if other_end < start {
+                    continue;
+                }
+                if other_start > end {
+                    break;
+                }
+                if other_start > start {
+                    ranges.push((start, other_start - 1));
+                }
+                start = other_end.saturating_add(1);
+                if start > end {
+                    break;
+                }
+            }
+            if start <= end {
+                ranges.push((start, end));
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Get characters which belongs to only one of charsets
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.difference(other).union(&other.difference(self))
+    }
+
+    /// Find glyphs of characters using font
+    ///
+    /// Characters which font does not cover are reported as missing.
+    pub fn resolve<F: FontMetricsExt>(&self, font: &F) -> CharsetGlyphs<F::Glyph> {
+        let mut glyphs = Vec::new();
+        let mut missing = Charset::new();
+
+        for chr in self.iter() {
+            if let Some(glyph) = font.glyph_index(chr) {
+                glyphs.push((chr, glyph));
+            } else {
+                missing.insert(chr);
+            }
+        }
+
+        CharsetGlyphs { glyphs, missing }
+    }
+
+    fn insert_codepoints(&mut self, start: u32, end: u32) {
+        // find ranges which overlaps or touches the new one
+        let first = self
+            .ranges
+            .partition_point(|&(_, range_end)| range_end.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(range_start, _)| range_start <= end.saturating_add(1));
+
+        if first < last {
+            let start = start.min(self.ranges[first].0);
+            let end = end.max(self.ranges[last - 1].1);
+            self.ranges.splice(first..last, [(start, end)]);
+        } else {
+            self.ranges.insert(first, (start, end));
+        }
+    }
+
+    fn from_ranges(mut ranges: Vec<(u32, u32)>) -> Self {
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+}
+
+/// Count surrogate codepoints in range
+fn surrogates(start: u32, end: u32) -> u32 {
+    let (start, end) = (start.max(0xd800), end.min(0xdfff));
+    if start <= end {
+        end - start + 1
+    } else {
+        0
+    }
+}
+
+impl FromStr for Charset {
+    type Err = CharsetError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+impl FromIterator<char> for Charset {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        Self::from_ranges(
+            iter.into_iter()
+                .map(|chr| (chr as u32, chr as u32))
+                .collect(),
+        )
+    }
+}
+
+impl Extend<char> for Charset {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        *self = self.union(&iter.into_iter().collect());
+    }
+}
+
+impl<'a> IntoIterator for &'a Charset {
+    type Item = char;
+    type IntoIter = Box<dyn Iterator<Item = char> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+impl From<&str> for Charset {
+    fn from(string: &str) -> Self {
+        string.chars().collect()
+    }
+}
+
+impl From<RangeInclusive<char>> for Charset {
+    fn from(range: RangeInclusive<char>) -> Self {
+        let mut charset = Self::new();
+        charset.insert_range(range);
+        charset
+    }
+}
+
+impl core::ops::BitOr for &Charset {
+    type Output = Charset;
+
+    fn bitor(self, other: Self) -> Charset {
+        self.union(other)
+    }
+}
+
+impl core::ops::BitAnd for &Charset {
+    type Output = Charset;
+
+    fn bitand(self, other: Self) -> Charset {
+        self.intersection(other)
+    }
+}
+
+impl core::ops::BitXor for &Charset {
+    type Output = Charset;
+
+    fn bitxor(self, other: Self) -> Charset {
+        self.symmetric_difference(other)
+    }
+}
+
+impl core::ops::Sub for &Charset {
+    type Output = Charset;
+
+    fn sub(self, other: Self) -> Charset {
+        self.difference(other)
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            position: 0,
+        }
+    }
+
+    fn error<T>(&self, message: &'static str) -> Result<T, CharsetError> {
+        Err(CharsetError {
+            position: self.position,
+            message,
+        })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let chr = self.peek()?;
+        self.position += chr.len_utf8();
+        Some(chr)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(chr) if chr.is_whitespace()) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char, message: &'static str) -> Result<(), CharsetError> {
+        if self.peek() == Some(expected) {
+            self.next();
+            Ok(())
+        } else {
+            self.error(message)
+        }
+    }
+
+    fn parse(mut self) -> Result<Charset, CharsetError> {
+        let mut ranges = Vec::new();
+
+        self.skip_whitespace();
+
+        while self.peek().is_some() {
+            match self.peek() {
+                Some('"') => {
+                    self.next();
+                    loop {
+                        match self.peek() {
+                            Some('"') => {
+                                self.next();
+                                break;
+                            }
+                            Some(_) => {
+                                let code = self.parse_quoted_char()? as u32;
+                                ranges.push((code, code));
+                            }
+                            None => return self.error("unterminated string"),
+                        }
+                    }
+                }
+                Some('[') => {
+                    self.next();
+                    self.skip_whitespace();
+                    let start = self.parse_codepoint()?;
+                    self.skip_whitespace();
+                    self.expect(',', "expected ','")?;
+                    self.skip_whitespace();
+                    let position = self.position;
+                    let end = self.parse_codepoint()?;
+                    self.skip_whitespace();
+                    self.expect(']', "expected ']'")?;
+                    if start > end {
+                        return Err(CharsetError {
+                            position,
+                            message: "range end is less than start",
+                        });
+                    }
+                    ranges.push((start, end));
+                }
+                _ => {
+                    let code = self.parse_codepoint()?;
+                    ranges.push((code, code));
+                }
+            }
+
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(',') => {
+                    self.next();
+                    self.skip_whitespace();
+                }
+                Some(_) => return self.error("expected ','"),
+                None => {}
+            }
+        }
+
+        Ok(Charset::from_ranges(ranges))
+    }
+
+    fn parse_codepoint(&mut self) -> Result<u32, CharsetError> {
+        match self.peek() {
+            Some('\'') => {
+                self.next();
+                let chr = self.parse_quoted_char()?;
+                self.expect('\'', "expected '")?;
+                Ok(chr as u32)
+            }
+            Some(chr) if chr.is_ascii_digit() => self.parse_number(),
+            Some(_) => self.error("expected character or codepoint"),
+            None => self.error("unexpected end"),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<u32, CharsetError> {
+        let start = self.position;
+        let rest = &self.source[start..];
+        let (digits, radix, prefix) = if rest.starts_with("0x") || rest.starts_with("0X") {
+            (&rest[2..], 16, 2)
+        } else {
+            (rest, 10, 0)
+        };
+        let length = digits
+            .find(|chr: char| !chr.is_digit(radix))
+            .unwrap_or(digits.len());
+
+        if length == 0 {
+            return self.error("expected number");
+        }
+
+        match u32::from_str_radix(&digits[..length], radix) {
+            Ok(code) if code <= MAX_CODEPOINT => {
+                self.position += prefix + length;
+                Ok(code)
+            }
+            _ => self.error("codepoint out of range"),
+        }
+    }
+
+    fn parse_quoted_char(&mut self) -> Result<char, CharsetError> {
+        match self.next() {
+            Some('\\') => match self.next() {
+                Some('n') => Ok('\n'),
+                Some('r') => Ok('\r'),
+                Some('t') => Ok('\t'),
+                Some('0') => Ok('\0'),
+                Some(chr @ ('\\' | '\'' | '"')) => Ok(chr),
+                Some(chr) => {
+                    self.position -= chr.len_utf8();
+                    self.error("unknown escape sequence")
+                }
+                None => self.error("unexpected end"),
+            },
+            Some(chr) => Ok(chr),
+            None => self.error("unexpected end"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_items() {
+        let charset =
+            Charset::parse(r#"[0x20, 0x7e], 'Ä', "abc", 0x3b1, ['α', 'γ'], '\''"#).unwrap();
+
+        assert_eq!(
+            charset.ranges().collect::<Vec<_>>(),
+            [0x20..=0x7e, 0xc4..=0xc4, 0x3b1..=0x3b3]
+        );
+        assert_eq!(charset.len(), 95 + 1 + 3);
+        assert!(charset.contains('Ä'));
+        assert!(!charset.contains('ä'));
+        assert_eq!(
+            charset,
+            r#""abc""#.parse::<Charset>().unwrap().union(&charset)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Charset::parse("").unwrap(), Charset::new());
+        assert_eq!(Charset::parse("'a' 'b'").unwrap_err().position, 4);
+        assert_eq!(Charset::parse("[0x7e, 0x20]").unwrap_err().position, 7);
+        assert_eq!(Charset::parse("\"abc").unwrap_err().position, 4);
+        assert_eq!(Charset::parse("0x110000").unwrap_err().position, 0);
+        assert_eq!(Charset::parse("'a', x").unwrap_err().position, 5);
+    }
+
+    #[test]
+    fn set_operations() {
+        let letters = Charset::from('a'..='z');
+        let vowels = Charset::from("aeiou");
+
+        let consonants = &letters - &vowels;
+        assert_eq!(consonants.len(), 21);
+        assert!(!consonants.contains('e'));
+
+        assert_eq!(&consonants | &vowels, letters);
+        assert_eq!(&letters & &vowels, vowels);
+        assert!((&consonants & &vowels).is_empty());
+        assert_eq!(
+            (&Charset::from("abc") ^ &Charset::from("bcd")),
+            Charset::from("ad")
+        );
+    }
+
+    #[test]
+    fn iterate_chars() {
+        let mut charset = Charset::from("ca");
+        charset.insert('b');
+        charset.insert_range('x'..='z');
+
+        assert_eq!(
+            charset.ranges().collect::<Vec<_>>(),
+            [0x61..=0x63, 0x78..=0x7a]
+        );
+        assert_eq!(charset.iter().collect::<String>(), "abcxyz");
+
+        // surrogates are skipped
+        let charset = Charset::parse("[0xd7ff, 0xe000]").unwrap();
+        assert_eq!(charset.len(), 2);
+        assert_eq!(charset.iter().count(), 2);
+    }
+
+    #[cfg(feature = "ttf-parser")]
+    #[test]
+    fn resolve_glyphs() {
+        use notosans::REGULAR_TTF;
+        use ttf_parser::Face;
+
+        let font = Face::parse(REGULAR_TTF, 0).unwrap();
+        let charset = Charset::parse("'A', 'B', 0xe000").unwrap();
+        let resolved = charset.resolve(&font);
+
+        assert_eq!(resolved.glyphs.len(), 2);
+        assert_eq!(resolved.glyphs[0].0, 'A');
+        assert_eq!(resolved.missing, Charset::from("\u{e000}"));
+    }
+}
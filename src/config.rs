@@ -2,6 +2,7 @@ use crate::ffi;
 
 /// Error correction mode
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum ErrorCorrectionMode {
     /// Skips error correction pass
@@ -25,6 +26,7 @@ impl Default for ErrorCorrectionMode {
 
 /// Configuration of whether to use an algorithm that computes the exact shape distance at the positions of suspected artifacts. This algorithm can be much slower
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum DistanceCheckMode {
     /// Never computes exact shape distance
@@ -50,6 +52,10 @@ pub struct ErrorCorrectionConfig {
     raw: ffi::msdfgen_ErrorCorrectionConfig,
 }
 
+// SAFETY: the only pointer in config is the error correction `buffer` which is always null
+unsafe impl Send for ErrorCorrectionConfig {}
+unsafe impl Sync for ErrorCorrectionConfig {}
+
 impl AsRef<ErrorCorrectionConfig> for ErrorCorrectionConfig {
     fn as_ref(&self) -> &ErrorCorrectionConfig {
         self
@@ -236,6 +242,11 @@ pub struct MsdfGeneratorConfig {
     raw: ffi::msdfgen_MSDFGeneratorConfig,
 }
 
+// SAFETY: `GeneratorConfig` holds only a `bool`, and the embedded
+// `ErrorCorrectionConfig errorCorrection` keeps its `buffer` null.
+unsafe impl Send for MsdfGeneratorConfig {}
+unsafe impl Sync for MsdfGeneratorConfig {}
+
 impl AsRef<MsdfGeneratorConfig> for MsdfGeneratorConfig {
     fn as_ref(&self) -> &MsdfGeneratorConfig {
         self
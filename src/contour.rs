@@ -6,6 +6,11 @@ pub struct Contour {
     raw: ffi::msdfgen_Contour,
 }
 
+// SAFETY: the only state is `std::vector<EdgeHolder> edges` owned by value,
+// and the const methods (`bound`, `winding`, ...) only read it.
+unsafe impl Send for Contour {}
+unsafe impl Sync for Contour {}
+
 impl Default for Contour {
     /// Creates new empty contour (with no edges)
     fn default() -> Self {
@@ -37,6 +42,28 @@ impl Contour {
         unsafe { &mut *(self.raw.addEdge2() as *mut EdgeHolder) }
     }
 
+    /// Gets the edges of the contour.
+    pub fn edges(&self) -> &[EdgeHolder] {
+        let len = unsafe { ffi::msdfgen_Contour_edges_len(&self.raw) };
+        if len == 0 {
+            &[]
+        } else {
+            let data = unsafe { ffi::msdfgen_Contour_edges_data(&self.raw) };
+            unsafe { core::slice::from_raw_parts(data as *const EdgeHolder, len) }
+        }
+    }
+
+    /// Gets the edges of the contour (mutable).
+    pub fn edges_mut(&mut self) -> &mut [EdgeHolder] {
+        let len = unsafe { ffi::msdfgen_Contour_edges_len(&self.raw) };
+        if len == 0 {
+            &mut []
+        } else {
+            let data = unsafe { ffi::msdfgen_Contour_edges_data(&self.raw) };
+            unsafe { core::slice::from_raw_parts_mut(data as *mut EdgeHolder, len) }
+        }
+    }
+
     /// Adds segment as an edge to the contour.
     pub fn add_segment(&mut self, segment: impl EdgeSegment) {
         self.add_edge(&segment.into())
@@ -61,6 +88,16 @@ impl Contour {
         bound
     }
 
+    /// Gets the tight bounding box of the contour.
+    ///
+    /// Unlike [`Contour::get_bound`] the origin is not included.
+    /// The bounding box is empty when contour has no edges.
+    pub fn get_tight_bound(&self) -> Bound<f64> {
+        let mut bound = Bound::empty();
+        self.bound(&mut bound);
+        bound
+    }
+
     /// Adjusts the bounding box to fit the contour border's mitered corners.
     pub fn bound_miters(
         &self,
@@ -98,4 +135,9 @@ impl Contour {
     pub fn winding(&self) -> i32 {
         unsafe { self.raw.winding() }
     }
+
+    /// Reverses the sequence of edges on the contour.
+    pub fn reverse(&mut self) {
+        unsafe { self.raw.reverse() }
+    }
 }
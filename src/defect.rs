@@ -0,0 +1,432 @@
+use crate::{
+    geometry::{intersect, Bezier},
+    Bound, EdgeHolder, Shape,
+};
+use core::fmt;
+
+/// Defect of shape found by [`Shape::check`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShapeDefect {
+    /// Contour has no edges
+    EmptyContour {
+        /// Index of contour
+        contour: usize,
+    },
+    /// Edge end point doesn't coincide with the start point of next edge
+    /// (the first edge of contour for the last one)
+    Unclosed {
+        /// Index of contour
+        contour: usize,
+        /// Index of edge
+        edge: usize,
+        /// Distance between the end point and the start of next edge
+        gap: f64,
+    },
+    /// Edge has NaN or infinite coordinates
+    NonFinite {
+        /// Index of contour
+        contour: usize,
+        /// Index of edge
+        edge: usize,
+    },
+    /// Edge has zero length (all points coincide)
+    ZeroLength {
+        /// Index of contour
+        contour: usize,
+        /// Index of edge
+        edge: usize,
+    },
+    /// Edges of the same contour intersect each other
+    SelfIntersection {
+        /// Index of contour
+        contour: usize,
+        /// Index of edge
+        edge: usize,
+        /// Index of other edge
+        other_edge: usize,
+    },
+}
+
+impl fmt::Display for ShapeDefect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyContour { contour } => write!(f, "contour {contour} is empty"),
+            Self::Unclosed { contour, edge, gap } => {
+                write!(f, "gap {gap} after edge {edge} of contour {contour}")
+            }
+            Self::NonFinite { contour, edge } => {
+                write!(f, "non-finite edge {edge} of contour {contour}")
+            }
+            Self::ZeroLength { contour, edge } => {
+                write!(f, "zero-length edge {edge} of contour {contour}")
+            }
+            Self::SelfIntersection {
+                contour,
+                edge,
+                other_edge,
+            } => write!(
+                f,
+                "edges {edge} and {other_edge} of contour {contour} intersect"
+            ),
+        }
+    }
+}
+
+impl Shape {
+    /// Checks shape and reports all found defects
+    ///
+    /// Unlike [`Shape::validate`] this also detects empty contours,
+    /// zero-length edges and self-intersecting contours.
+    pub fn check(&self) -> Result<(), Vec<ShapeDefect>> {
+        let mut defects = Vec::new();
+        let tolerance = tolerance(self);
+
+        for (contour_index, contour) in self.contours().iter().enumerate() {
+            let edges = contour.edges();
+            if edges.is_empty() {
+                defects.push(ShapeDefect::EmptyContour {
+                    contour: contour_index,
+                });
+                continue;
+            }
+
+            // curves of edges which are suitable for intersection test
+            let mut curves = Vec::with_capacity(edges.len());
+
+            for (index, edge) in edges.iter().enumerate() {
+                let curve = Bezier::from_edge(edge);
+
+                if !is_finite(&curve) {
+                    defects.push(ShapeDefect::NonFinite {
+                        contour: contour_index,
+                        edge: index,
+                    });
+                    continue;
+                }
+
+                if curve.is_degenerate() {
+                    defects.push(ShapeDefect::ZeroLength {
+                        contour: contour_index,
+                        edge: index,
+                    });
+                } else {
+                    curves.push((index, curve));
+                }
+
+                let next = &edges[(index + 1) % edges.len()];
+                let gap = (*next.start_point() - *edge.end_point()).length();
+                if gap != 0.0 && !gap.is_nan() {
+                    defects.push(ShapeDefect::Unclosed {
+                        contour: contour_index,
+                        edge: index,
+                        gap,
+                    });
+                }
+            }
+
+            for (edge, other_edge) in self_intersections(&curves, tolerance) {
+                defects.push(ShapeDefect::SelfIntersection {
+                    contour: contour_index,
+                    edge,
+                    other_edge,
+                });
+            }
+        }
+
+        if defects.is_empty() {
+            Ok(())
+        } else {
+            Err(defects)
+        }
+    }
+
+    /// Repairs easy defects of shape
+    ///
+    /// Empty contours, non-finite and zero-length edges are removed. Tiny gaps between edges
+    /// are closed by moving the end point of edge while bigger ones are bridged by lines.
+    /// Self-intersections are not repaired (see [`Shape::resolve_overlaps`]).
+    ///
+    /// Returns the defects which remain after repair.
+    pub fn auto_repair(&mut self) -> Result<(), Vec<ShapeDefect>> {
+        let tolerance = tolerance(self);
+        let mut shape = Shape::default();
+        shape.set_inverse_y_axis(self.get_inverse_y_axis());
+
+        for contour in self.contours() {
+            let edges: Vec<&EdgeHolder> = contour
+                .edges()
+                .iter()
+                .filter(|edge| {
+                    let curve = Bezier::from_edge(edge);
+                    is_finite(&curve) && !curve.is_degenerate()
+                })
+                .collect();
+
+            if edges.is_empty() {
+                continue;
+            }
+
+            let repaired = shape.add_contour_mut();
+            for (index, edge) in edges.iter().enumerate() {
+                let end = *edge.end_point();
+                let next_start = *edges[(index + 1) % edges.len()].start_point();
+                let gap = (next_start - end).length();
+
+                repaired.add_edge(edge);
+                if gap > tolerance {
+                    repaired.add_edge(&EdgeHolder::new_linear(end, next_start, *edge.edge_color()));
+                } else if gap > 0.0 {
+                    let last = repaired.edges_mut().last_mut().unwrap();
+                    *last.end_point_mut() = next_start;
+                }
+            }
+        }
+
+        *self = shape;
+        self.check()
+    }
+}
+
+/// Computes the tolerance of coincidence of points relative to the size of shape
+///
+/// Non-finite edges are ignored to not make the tolerance infinite.
+fn tolerance(shape: &Shape) -> f64 {
+    let mut bound = Bound::empty();
+
+    for contour in shape.contours() {
+        for curve in contour.edges().iter().map(Bezier::from_edge) {
+            if is_finite(&curve) {
+                let (min, max) = curve.control_bound();
+                bound.left = bound.left.min(min.x);
+                bound.bottom = bound.bottom.min(min.y);
+                bound.right = bound.right.max(max.x);
+                bound.top = bound.top.max(max.y);
+            }
+        }
+    }
+
+    if bound.is_empty() {
+        0.0
+    } else {
+        bound.size() * 1e-9
+    }
+}
+
+fn is_finite(curve: &Bezier) -> bool {
+    curve
+        .points()
+        .iter()
+        .all(|point| point.x.is_finite() && point.y.is_finite())
+}
+
+/// Finds pairs of intersecting curves of contour
+///
+/// The intersections at common end points of adjacent curves are ignored.
+fn self_intersections(curves: &[(usize, Bezier)], tolerance: f64) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+
+    for (position, (index, curve)) in curves.iter().enumerate() {
+        for (other_index, other) in &curves[position + 1..] {
+            let mut params = Vec::new();
+            let mut other_params = Vec::new();
+            intersect(curve, other, tolerance, &mut params, &mut other_params);
+
+            // the points where curves touch each other at ends
+            let joints: Vec<_> = [curve.start(), curve.end()]
+                .into_iter()
+                .filter(|point| *point == other.start() || *point == other.end())
+                .collect();
+
+            let is_crossing = |curve: &Bezier, t: &f64| {
+                (0.0..=1.0).contains(t) && {
+                    let point = curve.point(*t);
+                    joints
+                        .iter()
+                        .all(|joint| (*joint - point).length() > tolerance * 1e2)
+                }
+            };
+
+            if params.iter().any(|t| is_crossing(curve, t))
+                || other_params.iter().any(|t| is_crossing(other, t))
+            {
+                pairs.push((*index, *other_index));
+            }
+        }
+    }
+
+    pairs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{EdgeColor, Point2};
+
+    #[test]
+    fn defects() {
+        assert_eq!(
+            Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z")
+                .unwrap()
+                .check(),
+            Ok(())
+        );
+
+        let mut shape = Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z").unwrap();
+        shape.add_contour_mut();
+        {
+            let contour = shape.add_contour_mut();
+            contour.add_edge(&EdgeHolder::new_linear(
+                (0.0, 0.0),
+                (5.0, 0.0),
+                EdgeColor::White,
+            ));
+            contour.add_edge(&EdgeHolder::new_linear(
+                (5.0, 0.0),
+                (5.0, 0.0),
+                EdgeColor::White,
+            ));
+            contour.add_edge(&EdgeHolder::new_linear(
+                (5.0, 0.5),
+                (0.0, 0.0),
+                EdgeColor::White,
+            ));
+            contour.add_edge(&EdgeHolder::new_linear(
+                (0.0, f64::NAN),
+                (0.0, 0.0),
+                EdgeColor::White,
+            ));
+        }
+        assert!(!shape.validate());
+
+        let defects = shape.check().unwrap_err();
+        assert_eq!(
+            defects,
+            [
+                ShapeDefect::EmptyContour { contour: 1 },
+                ShapeDefect::ZeroLength {
+                    contour: 2,
+                    edge: 1
+                },
+                ShapeDefect::Unclosed {
+                    contour: 2,
+                    edge: 1,
+                    gap: 0.5
+                },
+                ShapeDefect::NonFinite {
+                    contour: 2,
+                    edge: 3
+                },
+            ]
+        );
+        assert_eq!(defects[2].to_string(), "gap 0.5 after edge 1 of contour 2");
+    }
+
+    #[test]
+    fn self_intersection() {
+        let bowtie = Shape::from_svg_path_data("M0 0 L10 10 L10 0 L0 10 Z").unwrap();
+        assert!(bowtie.validate());
+        assert_eq!(
+            bowtie.check(),
+            Err(vec![ShapeDefect::SelfIntersection {
+                contour: 0,
+                edge: 0,
+                other_edge: 2
+            }])
+        );
+
+        let circle =
+            Shape::from_svg_path_data("M-10 0 A10 10 0 0 0 10 0 A10 10 0 0 0 -10 0 Z").unwrap();
+        assert_eq!(circle.check(), Ok(()));
+    }
+
+    #[test]
+    fn repair() {
+        let mut shape = Shape::default();
+        shape.add_contour_mut();
+        {
+            let contour = shape.add_contour_mut();
+            contour.add_edge(&EdgeHolder::new_linear(
+                (0.0, 0.0),
+                (0.0, 10.0),
+                EdgeColor::Red,
+            ));
+            contour.add_edge(&EdgeHolder::new_linear(
+                (0.0, 10.0),
+                (0.0, 10.0),
+                EdgeColor::Red,
+            ));
+            contour.add_edge(&EdgeHolder::new_linear(
+                (0.0, 10.0 + 1e-12),
+                (10.0, 10.0),
+                EdgeColor::Green,
+            ));
+            contour.add_edge(&EdgeHolder::new_linear(
+                (10.0, 0.0),
+                (0.0, 0.0),
+                EdgeColor::Blue,
+            ));
+        }
+
+        assert_eq!(shape.auto_repair(), Ok(()));
+        assert!(shape.validate());
+        assert_eq!(shape.contours().len(), 1);
+
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 4);
+        assert_eq!(*edges[0].end_point(), Point2::new(0.0, 10.0 + 1e-12));
+        assert_eq!(*edges[2].start_point(), Point2::new(10.0, 10.0));
+        assert_eq!(*edges[2].edge_color(), EdgeColor::Green);
+    }
+
+    #[test]
+    fn non_finite_with_gap() {
+        let mut shape = Shape::default();
+        {
+            let contour = shape.add_contour_mut();
+            contour.add_edge(&EdgeHolder::new_linear(
+                (0.0, 0.0),
+                (0.0, 10.0),
+                EdgeColor::White,
+            ));
+            contour.add_edge(&EdgeHolder::new_linear(
+                (0.0, 10.0),
+                (10.0, 10.0),
+                EdgeColor::White,
+            ));
+            contour.add_edge(&EdgeHolder::new_linear(
+                (10.0, 5.0),
+                (0.0, 0.0),
+                EdgeColor::White,
+            ));
+        }
+        shape.add_contour_mut().add_edge(&EdgeHolder::new_linear(
+            (20.0, 0.0),
+            (f64::INFINITY, 0.0),
+            EdgeColor::White,
+        ));
+
+        assert_eq!(
+            shape.check(),
+            Err(vec![
+                ShapeDefect::Unclosed {
+                    contour: 0,
+                    edge: 1,
+                    gap: 5.0
+                },
+                ShapeDefect::NonFinite {
+                    contour: 1,
+                    edge: 0
+                },
+            ])
+        );
+
+        // the gap is bridged instead of snapped
+        assert_eq!(shape.auto_repair(), Ok(()));
+        assert_eq!(shape.contours().len(), 1);
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 4);
+        assert_eq!(*edges[1].end_point(), Point2::new(10.0, 10.0));
+        assert_eq!(*edges[2].start_point(), Point2::new(10.0, 10.0));
+        assert_eq!(*edges[2].end_point(), Point2::new(10.0, 5.0));
+    }
+}
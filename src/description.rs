@@ -0,0 +1,397 @@
+use crate::{Contour, EdgeColor, EdgeHolder, Point2, SegmentKind, Shape};
+use core::fmt;
+
+/// Error of shape description parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeDescriptionError {
+    /// Byte offset in description where error occurred
+    pub position: usize,
+    /// Error description
+    pub message: &'static str,
+}
+
+impl fmt::Display for ShapeDescriptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ShapeDescriptionError {}
+
+impl Shape {
+    /// Creates shape from description text
+    ///
+    /// The syntax is the same as used by `-defineshape` and `-shapedesc` options of msdfgen CLI:
+    /// each contour is enclosed in braces and consists of points separated by semicolons.
+    /// Control points of curves are given in parentheses between end points
+    /// and `#` stands for the starting point of contour.
+    ///
+    /// ```text
+    /// { 1471,0; 1149,0; 1021,333; 435,333; 314,0; 0,0; 571,1466; 880,1466; # }
+    /// { 926,580; 696,1150; 483,580; # }
+    /// ```
+    ///
+    /// Edge color may be given by code letter before control points (`c`, `m`, `y` and `w`
+    /// in either case). Contours without closing `#` are closed implicitly.
+    /// The `@invert-y` prefix sets inverse Y axis of shape.
+    pub fn from_description(description: &str) -> Result<Self, ShapeDescriptionError> {
+        DescriptionParser::new(description).parse()
+    }
+
+    /// Writes shape description
+    ///
+    /// The layout matches the output of msdfgen CLI. Edge colors are written only
+    /// when some edges are not white. Like upstream only cyan, magenta, yellow and white
+    /// colors have codes, so other colors are not preserved. See [`Shape::from_description`].
+    pub fn write_description(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        let colored = self.contours().iter().any(|contour| {
+            contour
+                .edges()
+                .iter()
+                .any(|edge| *edge.edge_color() != EdgeColor::White)
+        });
+
+        if self.get_inverse_y_axis() {
+            out.write_str("@invert-y\n")?;
+        }
+
+        for contour in self.contours() {
+            out.write_str("{\n")?;
+
+            let edges = contour.edges();
+            if !edges.is_empty() {
+                for edge in edges {
+                    let color = if colored {
+                        color_code(*edge.edge_color())
+                    } else {
+                        None
+                    };
+
+                    out.write_char('\t')?;
+                    write_point(out, edge.start_point())?;
+                    out.write_str(";\n")?;
+
+                    match edge.segment_kind() {
+                        SegmentKind::Linear => {
+                            if let Some(color) = color {
+                                writeln!(out, "\t\t{color};")?;
+                            }
+                        }
+                        SegmentKind::Quadratic => {
+                            out.write_str("\t\t")?;
+                            if let Some(color) = color {
+                                out.write_char(color)?;
+                            }
+                            out.write_char('(')?;
+                            write_point(out, edge.control_point(0).unwrap())?;
+                            out.write_str(");\n")?;
+                        }
+                        SegmentKind::Cubic => {
+                            out.write_str("\t\t")?;
+                            if let Some(color) = color {
+                                out.write_char(color)?;
+                            }
+                            out.write_char('(')?;
+                            write_point(out, edge.control_point(0).unwrap())?;
+                            out.write_str("; ")?;
+                            write_point(out, edge.control_point(1).unwrap())?;
+                            out.write_str(");\n")?;
+                        }
+                    }
+                }
+                out.write_str("\t#\n")?;
+            }
+
+            out.write_str("}\n")?;
+        }
+
+        Ok(())
+    }
+
+    /// Gets shape description
+    ///
+    /// See [`Shape::write_description`].
+    pub fn to_description(&self) -> String {
+        let mut out = String::new();
+        self.write_description(&mut out).unwrap();
+        out
+    }
+}
+
+fn write_point(out: &mut impl fmt::Write, point: &Point2<f64>) -> fmt::Result {
+    write!(out, "{}, {}", point.x, point.y)
+}
+
+fn color_code(color: EdgeColor) -> Option<char> {
+    Some(match color {
+        EdgeColor::Yellow => 'y',
+        EdgeColor::Magenta => 'm',
+        EdgeColor::Cyan => 'c',
+        EdgeColor::White => 'w',
+        _ => return None,
+    })
+}
+
+fn code_color(code: u8) -> Option<EdgeColor> {
+    Some(match code {
+        b'y' | b'Y' => EdgeColor::Yellow,
+        b'm' | b'M' => EdgeColor::Magenta,
+        b'c' | b'C' => EdgeColor::Cyan,
+        b'w' | b'W' => EdgeColor::White,
+        _ => return None,
+    })
+}
+
+struct DescriptionParser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> DescriptionParser<'a> {
+    fn new(data: &'a str) -> Self {
+        Self {
+            data: data.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn error<T>(&self, message: &'static str) -> Result<T, ShapeDescriptionError> {
+        Err(ShapeDescriptionError {
+            position: self.position,
+            message,
+        })
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        while matches!(self.data.get(self.position), Some(chr) if chr.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+        self.data.get(self.position).copied()
+    }
+
+    fn accept(&mut self, chr: u8) -> bool {
+        if self.peek() == Some(chr) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, chr: u8, message: &'static str) -> Result<(), ShapeDescriptionError> {
+        if self.accept(chr) {
+            Ok(())
+        } else {
+            self.error(message)
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, ShapeDescriptionError> {
+        self.peek();
+        let start = self.position;
+        while matches!(
+            self.data.get(self.position),
+            Some(b'0'..=b'9' | b'+' | b'-' | b'.' | b'e' | b'E')
+        ) {
+            self.position += 1;
+        }
+        core::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or(ShapeDescriptionError {
+                position: start,
+                message: "expected number",
+            })
+    }
+
+    fn point(&mut self) -> Result<Point2<f64>, ShapeDescriptionError> {
+        let x = self.number()?;
+        self.expect(b',', "expected comma")?;
+        let y = self.number()?;
+        Ok(Point2::new(x, y))
+    }
+
+    fn parse(mut self) -> Result<Shape, ShapeDescriptionError> {
+        let mut shape = Shape::default();
+
+        if self.peek() == Some(b'@') {
+            if self.data[self.position..].starts_with(b"@invert-y") {
+                self.position += b"@invert-y".len();
+                shape.set_inverse_y_axis(true);
+            } else {
+                return self.error("unknown directive");
+            }
+        }
+
+        while self.peek().is_some() {
+            self.expect(b'{', "expected contour")?;
+            shape.add_contour(&self.contour()?);
+        }
+
+        Ok(shape)
+    }
+
+    fn contour(&mut self) -> Result<Contour, ShapeDescriptionError> {
+        let mut contour = Contour::default();
+
+        if self.accept(b'}') {
+            return Ok(contour);
+        }
+
+        let start = self.point()?;
+        let mut point = start;
+
+        loop {
+            if self.accept(b'}') {
+                if point != start {
+                    contour.add_edge(&EdgeHolder::new_linear(point, start, EdgeColor::White));
+                }
+                return Ok(contour);
+            }
+
+            self.expect(b';', "expected semicolon")?;
+
+            let color = self.peek().and_then(code_color);
+            if color.is_some() {
+                self.position += 1;
+            }
+
+            let mut control_points = [None, None];
+            if self.accept(b'(') {
+                control_points[0] = Some(self.point()?);
+                if self.accept(b';') {
+                    control_points[1] = Some(self.point()?);
+                }
+                self.expect(b')', "expected closing parenthesis")?;
+            }
+
+            let has_spec = color.is_some() || control_points[0].is_some();
+            if has_spec {
+                self.expect(b';', "expected semicolon")?;
+            } else if self.peek() == Some(b'}') {
+                // trailing semicolon
+                continue;
+            }
+
+            let closed = self.accept(b'#');
+            let end_point = if closed { start } else { self.point()? };
+            let color = color.unwrap_or_default();
+
+            let edge = match control_points {
+                [None, _] => EdgeHolder::new_linear(point, end_point, color),
+                [Some(cpoint), None] => EdgeHolder::new_quadratic(point, cpoint, end_point, color),
+                [Some(c1point), Some(c2point)] => {
+                    EdgeHolder::new_cubic(point, c1point, c2point, end_point, color)
+                }
+            };
+            contour.add_edge(&edge);
+            point = end_point;
+
+            if closed {
+                self.expect(b'}', "expected end of contour")?;
+                return Ok(contour);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn upstream_example() {
+        let shape = Shape::from_description(
+            "{ 1471,0; 1149,0; 1021,333; 435,333; 314,0; 0,0; 571,1466; 880,1466; # }\
+             { 926,580; 696,1150; 483,580; # }",
+        )
+        .unwrap();
+
+        assert_eq!(shape.contours().len(), 2);
+        assert_eq!(shape.contours()[0].edges().len(), 8);
+        assert_eq!(shape.contours()[1].edges().len(), 3);
+        assert!(shape.validate());
+        assert!(!shape.get_inverse_y_axis());
+
+        let edge = &shape.contours()[0].edges()[7];
+        assert_eq!(*edge.start_point(), Point2::new(880.0, 1466.0));
+        assert_eq!(*edge.end_point(), Point2::new(1471.0, 0.0));
+    }
+
+    #[test]
+    fn curves_and_colors() {
+        let shape =
+            Shape::from_description("@invert-y { 0,0; M(0,1); 1,1; y(2,1; 2,0.5); 1,0; C; # } {}")
+                .unwrap();
+
+        assert!(shape.get_inverse_y_axis());
+        assert_eq!(shape.contours().len(), 2);
+
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges[0].segment_kind(), SegmentKind::Quadratic);
+        assert_eq!(*edges[0].edge_color(), EdgeColor::Magenta);
+        assert_eq!(edges[1].segment_kind(), SegmentKind::Cubic);
+        assert_eq!(*edges[1].control_point(1).unwrap(), Point2::new(2.0, 0.5));
+        assert_eq!(*edges[1].edge_color(), EdgeColor::Yellow);
+        assert_eq!(edges[2].segment_kind(), SegmentKind::Linear);
+        assert_eq!(*edges[2].edge_color(), EdgeColor::Cyan);
+        assert_eq!(*edges[2].end_point(), Point2::new(0.0, 0.0));
+
+        let description = shape.to_description();
+        assert_eq!(
+            description,
+            "@invert-y\n{\n\t0, 0;\n\t\tm(0, 1);\n\t1, 1;\n\t\ty(2, 1; 2, 0.5);\n\t1, 0;\n\t\tc;\n\t#\n}\n{\n}\n"
+        );
+        assert_eq!(
+            Shape::from_description(&description)
+                .unwrap()
+                .to_description(),
+            description
+        );
+    }
+
+    #[test]
+    fn single_channel_colors() {
+        let mut shape = Shape::from_description("{ 0,0; y; 1,0; 1,1; # }").unwrap();
+        *shape.contours_mut()[0].edges_mut()[1].edge_color_mut() = EdgeColor::Red;
+
+        // colors without codes are skipped like upstream does
+        assert_eq!(
+            shape.to_description(),
+            "{\n\t0, 0;\n\t\ty;\n\t1, 0;\n\t1, 1;\n\t\tw;\n\t#\n}\n"
+        );
+        assert_eq!(
+            Shape::from_description("{ 0,0; r; 1,0; # }")
+                .err()
+                .unwrap()
+                .message,
+            "expected number"
+        );
+    }
+
+    #[test]
+    fn implicit_close() {
+        let shape = Shape::from_description("{ 0,0; 1,0; 1,1; }").unwrap();
+        assert_eq!(shape.contours()[0].edges().len(), 3);
+        assert!(shape.validate());
+
+        // colors are omitted when all edges are white
+        assert_eq!(
+            shape.to_description(),
+            "{\n\t0, 0;\n\t1, 0;\n\t1, 1;\n\t#\n}\n"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let error = |data| Shape::from_description(data).err().unwrap();
+
+        assert_eq!(error("0,0; 1,1").position, 0);
+        assert_eq!(error("{ 0,0; 1 1 }").message, "expected comma");
+        assert_eq!(error("{ 0,0; (1,1; 2,2; 1,0 }").position, 16);
+        assert_eq!(error("{ 0,0; 1,0; # 1,1 }").position, 14);
+        assert_eq!(error("{ 0,0; x,1 }").message, "expected number");
+        assert_eq!(error("@flip { }").message, "unknown directive");
+    }
+}
@@ -0,0 +1,419 @@
+use crate::{
+    atlas::GlyphImage, AtlasConfig, AtlasPlacement, Bitmap, Bound, FieldGeneration, FontMetricsExt,
+};
+use bytemuck::Pod;
+use std::{collections::HashMap, hash::Hash};
+
+/// Glyph of dynamic atlas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynamicGlyph {
+    /// Horizontal advance (in ems)
+    pub advance: f64,
+    /// Placement of glyph image (`None` for glyphs without outline like space)
+    ///
+    /// The page index is always zero. The placement stays valid until glyph is evicted.
+    pub placement: Option<AtlasPlacement>,
+}
+
+struct Entry {
+    glyph: DynamicGlyph,
+    last_used: u64,
+}
+
+/// Font atlas which generates distance fields of glyphs on demand
+///
+/// The atlas owns single bitmap of fixed size (the page size of config).
+/// When the bitmap has no space for new glyph the least recently used glyphs are evicted.
+/// The regions of bitmap which has been changed are collected as dirty rectangles
+/// so only those need to be uploaded to GPU.
+pub struct DynamicAtlas<T, G> {
+    config: AtlasConfig,
+    bitmap: Bitmap<T>,
+    allocator: ShelfAllocator,
+    entries: HashMap<G, Entry>,
+    dirty: Vec<Bound<u32>>,
+    clock: u64,
+}
+
+impl<T: FieldGeneration + Pod, G: Copy + Eq + Hash> DynamicAtlas<T, G> {
+    /// Creates empty atlas using config
+    pub fn new(config: impl AsRef<AtlasConfig>) -> Self {
+        let config = *config.as_ref();
+        let (width, height) = config.get_page_size();
+
+        Self {
+            config,
+            bitmap: Bitmap::new(width, height),
+            allocator: ShelfAllocator::new(width, height),
+            entries: HashMap::new(),
+            dirty: Vec::new(),
+            clock: 0,
+        }
+    }
+
+    /// Gets glyph generating it when requested first time
+    ///
+    /// Glyphs which was not used for longest time are evicted when there is no space.
+    ///
+    /// Returns `None` when font has no such glyph or glyph does not fit into atlas.
+    pub fn glyph<F>(&mut self, font: &F, glyph: G) -> Option<DynamicGlyph>
+    where
+        F: FontMetricsExt<Glyph = G>,
+    {
+        self.clock += 1;
+
+        if let Some(entry) = self.entries.get_mut(&glyph) {
+            entry.last_used = self.clock;
+            return Some(entry.glyph);
+        }
+
+        let units_per_em = font.units_per_em();
+        let advance = font.glyph_advance(glyph)? / units_per_em;
+
+        let placement = if let Some(image) = font
+            .glyph_shape(glyph)
+            .and_then(|shape| GlyphImage::new(shape, units_per_em, &self.config))
+        {
+            let (x, y) = self.allocate(image.width, image.height)?;
+            let atlas_bound = Bound::new(x, y, x + image.width, y + image.height);
+
+            self.bitmap
+                .copy_from(&image.generate::<T>(&self.config), x, y);
+            self.dirty.push(atlas_bound);
+
+            Some(AtlasPlacement {
+                page: 0,
+                plane_bound: image.plane_bound,
+                atlas_bound,
+            })
+        } else {
+            None
+        };
+
+        let glyph_info = DynamicGlyph { advance, placement };
+
+        self.entries.insert(
+            glyph,
+            Entry {
+                glyph: glyph_info,
+                last_used: self.clock,
+            },
+        );
+
+        Some(glyph_info)
+    }
+
+    /// Gets glyph for character
+    ///
+    /// See [`DynamicAtlas::glyph`].
+    pub fn char_glyph<F>(&mut self, font: &F, chr: char) -> Option<DynamicGlyph>
+    where
+        F: FontMetricsExt<Glyph = G>,
+    {
+        let glyph = font.glyph_index(chr)?;
+        self.glyph(font, glyph)
+    }
+
+    fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        // eviction cannot help when rectangle is bigger than page
+        if width > self.bitmap.width() || height > self.bitmap.height() {
+            return None;
+        }
+
+        loop {
+            if let Some(place) = self.allocator.allocate(width, height) {
+                return Some(place);
+            }
+
+            // evict least recently used glyph which occupies space
+            let (&glyph, _) = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| entry.glyph.placement.is_some())
+                .min_by_key(|(_, entry)| entry.last_used)?;
+
+            self.evict(glyph);
+        }
+    }
+}
+
+impl<T, G: Copy + Eq + Hash> DynamicAtlas<T, G> {
+    /// Gets atlas bitmap
+    pub fn bitmap(&self) -> &Bitmap<T> {
+        &self.bitmap
+    }
+
+    /// Gets atlas config
+    pub fn config(&self) -> &AtlasConfig {
+        &self.config
+    }
+
+    /// Checks that glyph is in atlas
+    pub fn contains(&self, glyph: G) -> bool {
+        self.entries.contains_key(&glyph)
+    }
+
+    /// Gets number of glyphs in atlas
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks that atlas has no glyphs
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes glyph from atlas
+    ///
+    /// Returns `false` when atlas has no such glyph.
+    pub fn evict(&mut self, glyph: G) -> bool {
+        if let Some(entry) = self.entries.remove(&glyph) {
+            if let Some(placement) = entry.glyph.placement {
+                self.allocator
+                    .deallocate(placement.atlas_bound.left, placement.atlas_bound.bottom);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes all glyphs from atlas
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.allocator = ShelfAllocator::new(self.bitmap.width(), self.bitmap.height());
+    }
+
+    /// Gets rectangles of bitmap which has been changed since last call (in pixels, y-up)
+    pub fn take_dirty(&mut self) -> Vec<Bound<u32>> {
+        core::mem::take(&mut self.dirty)
+    }
+}
+
+struct Shelf {
+    y: u32,
+    height: u32,
+    /// Allocated slots as x and width sorted by x
+    slots: Vec<(u32, u32)>,
+}
+
+impl Shelf {
+    fn find_gap(&self, width: u32, shelf_width: u32) -> Option<(usize, u32)> {
+        let mut x = 0;
+        for (index, &(slot_x, slot_width)) in self.slots.iter().enumerate() {
+            if slot_x - x >= width {
+                return Some((index, x));
+            }
+            x = slot_x + slot_width;
+        }
+        if shelf_width - x >= width {
+            Some((self.slots.len(), x))
+        } else {
+            None
+        }
+    }
+}
+
+/// Shelf allocator which supports deallocation
+struct ShelfAllocator {
+    width: u32,
+    height: u32,
+    shelves: Vec<Shelf>,
+}
+
+impl ShelfAllocator {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            shelves: Vec::new(),
+        }
+    }
+
+    /// Allocates rectangle and returns its position
+    fn allocate(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+        if width > self.width || height > self.height {
+            return None;
+        }
+
+        // use lowest shelf which has enough space
+        let best = self
+            .shelves
+            .iter()
+            .enumerate()
+            .filter(|(_, shelf)| height <= shelf.height)
+            .filter_map(|(index, shelf)| {
+                let (slot, x) = shelf.find_gap(width, self.width)?;
+                Some((index, slot, x, shelf.height))
+            })
+            .min_by_key(|&(index, _, _, shelf_height)| (shelf_height, index));
+
+        if let Some((index, slot, x, shelf_height)) = best {
+            let shelf = &mut self.shelves[index];
+
+            // split empty shelf to not waste its height
+            if shelf.slots.is_empty() && height < shelf_height {
+                shelf.height = height;
+                let y = shelf.y + height;
+                self.shelves.insert(
+                    index + 1,
+                    Shelf {
+                        y,
+                        height: shelf_height - height,
+                        slots: Vec::new(),
+                    },
+                );
+            }
+
+            let shelf = &mut self.shelves[index];
+            shelf.slots.insert(slot, (x, width));
+            return Some((x, shelf.y));
+        }
+
+        let top = self
+            .shelves
+            .last()
+            .map(|shelf| shelf.y + shelf.height)
+            .unwrap_or_default();
+
+        if top + height <= self.height {
+            self.shelves.push(Shelf {
+                y: top,
+                height,
+                slots: vec![(0, width)],
+            });
+            return Some((0, top));
+        }
+
+        None
+    }
+
+    /// Deallocates rectangle at position
+    fn deallocate(&mut self, x: u32, y: u32) {
+        if let Some(shelf) = self.shelves.iter_mut().find(|shelf| shelf.y == y) {
+            shelf.slots.retain(|&(slot_x, _)| slot_x != x);
+        }
+
+        // merge adjacent empty shelves
+        let mut index = 0;
+        while index + 1 < self.shelves.len() {
+            if self.shelves[index].slots.is_empty() && self.shelves[index + 1].slots.is_empty() {
+                let next = self.shelves.remove(index + 1);
+                self.shelves[index].height += next.height;
+            } else {
+                index += 1;
+            }
+        }
+
+        // drop empty shelf at the end
+        if matches!(self.shelves.last(), Some(shelf) if shelf.slots.is_empty()) {
+            self.shelves.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shelf_allocator() {
+        let mut allocator = ShelfAllocator::new(10, 10);
+
+        assert_eq!(allocator.allocate(4, 4), Some((0, 0)));
+        assert_eq!(allocator.allocate(4, 3), Some((4, 0)));
+        assert_eq!(allocator.allocate(4, 4), Some((0, 4)));
+        assert_eq!(allocator.allocate(3, 3), Some((4, 4)));
+        assert_eq!(allocator.allocate(11, 1), None);
+        assert_eq!(allocator.allocate(4, 4), None);
+
+        // freed slot is reused
+        allocator.deallocate(4, 0);
+        assert_eq!(allocator.allocate(4, 4), Some((4, 0)));
+
+        assert_eq!(allocator.allocate(2, 2), Some((8, 0)));
+        assert_eq!(allocator.allocate(10, 2), Some((0, 8)));
+
+        // freed shelf is split to fit smaller rectangles
+        allocator.deallocate(0, 4);
+        allocator.deallocate(4, 4);
+        assert_eq!(allocator.allocate(3, 2), Some((0, 4)));
+        assert_eq!(allocator.allocate(5, 2), Some((3, 4)));
+        assert_eq!(allocator.allocate(10, 2), Some((0, 6)));
+        assert_eq!(allocator.allocate(2, 2), Some((8, 4)));
+        assert_eq!(allocator.allocate(1, 1), None);
+
+        // freed shelves at the end are dropped
+        allocator.deallocate(0, 8);
+        allocator.deallocate(0, 6);
+        assert_eq!(allocator.allocate(10, 4), Some((0, 6)));
+    }
+
+    #[test]
+    fn oversized_glyph() {
+        use crate::Rgb;
+
+        let config = AtlasConfig::default().with_page_size(10, 10);
+        let mut atlas = DynamicAtlas::<Rgb<f32>, u16>::new(config);
+
+        let (x, y) = atlas.allocate(4, 4).unwrap();
+        atlas.entries.insert(
+            1,
+            Entry {
+                glyph: DynamicGlyph {
+                    advance: 0.5,
+                    placement: Some(AtlasPlacement {
+                        page: 0,
+                        plane_bound: Bound::default(),
+                        atlas_bound: Bound::new(x, y, x + 4, y + 4),
+                    }),
+                },
+                last_used: 0,
+            },
+        );
+
+        // oversized glyphs don't evict anything
+        assert_eq!(atlas.allocate(11, 2), None);
+        assert_eq!(atlas.allocate(2, 11), None);
+        assert!(atlas.contains(1));
+
+        // glyphs which fit into page still evict others
+        assert_eq!(atlas.allocate(10, 10), Some((0, 0)));
+        assert!(!atlas.contains(1));
+    }
+
+    #[cfg(feature = "ttf-parser")]
+    #[test]
+    fn lru_eviction() {
+        use crate::Rgb;
+        use notosans::REGULAR_TTF;
+        use ttf_parser::Face;
+
+        let font = Face::parse(REGULAR_TTF, 0).unwrap();
+        let config = AtlasConfig::default()
+            .with_em_size(16.0)
+            .with_page_size(32, 20);
+
+        let mut atlas = DynamicAtlas::<Rgb<f32>, _>::new(config);
+
+        let space = atlas.char_glyph(&font, ' ').unwrap();
+        assert!(space.placement.is_none());
+        assert!(atlas.take_dirty().is_empty());
+
+        let h = atlas.char_glyph(&font, 'H').unwrap().placement.unwrap();
+        let m = atlas.char_glyph(&font, 'M').unwrap().placement.unwrap();
+        assert_eq!(atlas.take_dirty(), [h.atlas_bound, m.atlas_bound]);
+        assert!(atlas.take_dirty().is_empty());
+
+        // touch 'H' so 'M' becomes least recently used
+        assert_eq!(atlas.char_glyph(&font, 'H').unwrap().placement, Some(h));
+
+        let w = atlas.char_glyph(&font, 'W').unwrap().placement.unwrap();
+        assert_eq!(atlas.take_dirty(), [w.atlas_bound]);
+
+        let glyph = |chr| font.glyph_index(chr).unwrap();
+        assert!(atlas.contains(glyph('H')));
+        assert!(!atlas.contains(glyph('M')));
+        assert!(atlas.contains(glyph(' ')));
+    }
+}
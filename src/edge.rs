@@ -2,6 +2,7 @@ use crate::{ffi, CubicSegment, EdgeSegment, LinearSegment, Point2, QuadraticSegm
 
 /// Edge color enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum EdgeColor {
     Black = ffi::msdfgen_EdgeColor_BLACK,
@@ -32,6 +33,11 @@ pub struct EdgeHolder {
     raw: ffi::msdfgen_EdgeHolder,
 }
 
+// SAFETY: `EdgeSegment *edgeSegment` is cloned on copy and deleted on destruction,
+// so no other holder aliases it, and const segment methods never write through it.
+unsafe impl Send for EdgeHolder {}
+unsafe impl Sync for EdgeHolder {}
+
 impl Default for EdgeHolder {
     /// Creates new edge holder object
     fn default() -> Self {
@@ -0,0 +1,246 @@
+use crate::{Bitmap, FillRule, Framing, Gray, MsdfGeneratorConfig, Rgb, Rgba, Shape};
+
+/// Kind of distance field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldKind {
+    /// True signed distance field
+    Sdf,
+    /// Pseudo signed distance field
+    PseudoSdf,
+    /// Multi-channel signed distance field
+    Msdf,
+    /// Multi-channel signed distance field with true distance in the alpha channel
+    Mtsdf,
+}
+
+impl FieldKind {
+    /// Name of distance field type as used by msdf-atlas-gen
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Sdf => "sdf",
+            Self::PseudoSdf => "psdf",
+            Self::Msdf => "msdf",
+            Self::Mtsdf => "mtsdf",
+        }
+    }
+
+    /// Number of channels of distance field pixels
+    pub fn channels(&self) -> usize {
+        match self {
+            Self::Sdf | Self::PseudoSdf => 1,
+            Self::Msdf => 3,
+            Self::Mtsdf => 4,
+        }
+    }
+}
+
+/// Distance field of any kind
+///
+/// Allows choose kind of distance field at runtime.
+pub enum DistanceField {
+    /// True signed distance field
+    Sdf(Bitmap<Gray<f32>>),
+    /// Pseudo signed distance field
+    PseudoSdf(Bitmap<Gray<f32>>),
+    /// Multi-channel signed distance field
+    Msdf(Bitmap<Rgb<f32>>),
+    /// Multi-channel signed distance field with true distance in the alpha channel
+    Mtsdf(Bitmap<Rgba<f32>>),
+}
+
+macro_rules! with_bitmap {
+    ($field:expr, $bitmap:ident => $expr:expr) => {
+        match $field {
+            DistanceField::Sdf($bitmap) | DistanceField::PseudoSdf($bitmap) => $expr,
+            DistanceField::Msdf($bitmap) => $expr,
+            DistanceField::Mtsdf($bitmap) => $expr,
+        }
+    };
+}
+
+impl Clone for DistanceField {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Sdf(bitmap) => Self::Sdf(bitmap.clone()),
+            Self::PseudoSdf(bitmap) => Self::PseudoSdf(bitmap.clone()),
+            Self::Msdf(bitmap) => Self::Msdf(bitmap.clone()),
+            Self::Mtsdf(bitmap) => Self::Mtsdf(bitmap.clone()),
+        }
+    }
+}
+
+impl DistanceField {
+    /// Create new empty distance field of specified kind and size
+    pub fn new(kind: FieldKind, width: u32, height: u32) -> Self {
+        match kind {
+            FieldKind::Sdf => Self::Sdf(Bitmap::new(width, height)),
+            FieldKind::PseudoSdf => Self::PseudoSdf(Bitmap::new(width, height)),
+            FieldKind::Msdf => Self::Msdf(Bitmap::new(width, height)),
+            FieldKind::Mtsdf => Self::Mtsdf(Bitmap::new(width, height)),
+        }
+    }
+
+    /// Get kind of distance field
+    pub fn kind(&self) -> FieldKind {
+        match self {
+            Self::Sdf(_) => FieldKind::Sdf,
+            Self::PseudoSdf(_) => FieldKind::PseudoSdf,
+            Self::Msdf(_) => FieldKind::Msdf,
+            Self::Mtsdf(_) => FieldKind::Mtsdf,
+        }
+    }
+
+    /// Get width of bitmap in pixels
+    pub fn width(&self) -> u32 {
+        with_bitmap!(self, bitmap => bitmap.width())
+    }
+
+    /// Get height of bitmap in pixels
+    pub fn height(&self) -> u32 {
+        with_bitmap!(self, bitmap => bitmap.height())
+    }
+
+    /// Get raw pixel data
+    pub fn raw_pixels(&self) -> &[u8] {
+        with_bitmap!(self, bitmap => bitmap.raw_pixels())
+    }
+
+    /// Flip pixels around y axis
+    pub fn flip_y(&mut self) {
+        with_bitmap!(self, bitmap => bitmap.flip_y())
+    }
+
+    /// Fixes the sign of distance field, so that it matches the shape's rasterized fill
+    pub fn correct_sign(
+        &mut self,
+        shape: &Shape,
+        framing: impl AsRef<Framing<f64>>,
+        fill_rule: FillRule,
+    ) {
+        with_bitmap!(self, bitmap => shape.correct_sign(bitmap, framing, fill_rule))
+    }
+
+    /// Corrects MSDF error
+    ///
+    /// Does nothing for single-channel distance fields.
+    pub fn correct_msdf_error(
+        &mut self,
+        shape: &Shape,
+        framing: impl AsRef<Framing<f64>>,
+        config: impl AsRef<MsdfGeneratorConfig>,
+    ) {
+        match self {
+            Self::Sdf(_) | Self::PseudoSdf(_) => {}
+            Self::Msdf(bitmap) => shape.correct_msdf_error(bitmap, framing, config),
+            Self::Mtsdf(bitmap) => shape.correct_msdf_error(bitmap, framing, config),
+        }
+    }
+
+    /// Estimates the portion of the area that will be filled incorrectly when rendering using distance field
+    pub fn estimate_error(
+        &self,
+        shape: &Shape,
+        framing: impl AsRef<Framing<f64>>,
+        scanlines_per_row: u32,
+        fill_rule: FillRule,
+    ) -> f64 {
+        with_bitmap!(self, bitmap => {
+            shape.estimate_error(bitmap, framing, scanlines_per_row, fill_rule)
+        })
+    }
+
+    /// Renders distance field into grayscale bitmap
+    pub fn render(&self, target: impl AsMut<Bitmap<Gray<f32>>>, px_range: f64, mid_value: f32) {
+        with_bitmap!(self, bitmap => bitmap.render(target, px_range, mid_value))
+    }
+
+    /// Save distance field as png
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, writer: impl std::io::Write) -> Result<(), png::EncodingError> {
+        with_bitmap!(self, bitmap => bitmap.write_png(writer))
+    }
+}
+
+impl Shape {
+    /// Generate distance field of specified kind
+    ///
+    /// Pixel values are not clamped to `[-1.0, 1.0]`.
+    pub fn generate(
+        &self,
+        kind: FieldKind,
+        width: u32,
+        height: u32,
+        framing: impl AsRef<Framing<f64>>,
+        config: impl AsRef<MsdfGeneratorConfig>,
+    ) -> DistanceField {
+        let mut field = DistanceField::new(kind, width, height);
+        let config = config.as_ref();
+
+        match &mut field {
+            DistanceField::Sdf(bitmap) => self.generate_sdf(bitmap, framing, config),
+            DistanceField::PseudoSdf(bitmap) => self.generate_pseudo_sdf(bitmap, framing, config),
+            DistanceField::Msdf(bitmap) => self.generate_msdf(bitmap, framing, config),
+            DistanceField::Mtsdf(bitmap) => self.generate_mtsdf(bitmap, framing, config),
+        }
+
+        field
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Range, MID_VALUE};
+
+    #[test]
+    fn generate_kinds() {
+        let mut shape = Shape::from_svg_path_data("M1 1 L1 9 Q5 12 9 9 L9 1 Z").unwrap();
+        shape.normalize();
+        shape.edge_coloring_simple(3.0, 0);
+
+        let framing = shape
+            .get_bound()
+            .autoframe(16, 12, Range::Px(4.0), None)
+            .unwrap();
+        let config = MsdfGeneratorConfig::default();
+
+        for kind in [
+            FieldKind::Sdf,
+            FieldKind::PseudoSdf,
+            FieldKind::Msdf,
+            FieldKind::Mtsdf,
+        ] {
+            let mut field = shape.generate(kind, 16, 12, framing, config);
+            assert_eq!(field.kind(), kind);
+            assert_eq!((field.width(), field.height()), (16, 12));
+            assert_eq!(field.raw_pixels().len(), 16 * 12 * 4 * kind.channels());
+
+            field.correct_sign(&shape, framing, FillRule::NonZero);
+            field.correct_msdf_error(&shape, framing, config);
+            assert!(field
+                .estimate_error(&shape, framing, 4, FillRule::NonZero)
+                .is_finite());
+
+            let mut preview = Bitmap::<Gray<f32>>::new(32, 24);
+            field.render(&mut preview, 4.0, MID_VALUE);
+        }
+
+        let mut expected = Bitmap::<Rgb<f32>>::new(16, 12);
+        shape.generate_msdf(&mut expected, framing, config);
+        match shape.generate(FieldKind::Msdf, 16, 12, framing, config) {
+            DistanceField::Msdf(bitmap) => assert_eq!(bitmap.pixels(), expected.pixels()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn write_png() {
+        let field = DistanceField::new(FieldKind::Mtsdf, 4, 3);
+        let mut output = Vec::new();
+        field.write_png(&mut output).unwrap();
+
+        let bitmap = Bitmap::<Rgba<f32>>::read_png(output.as_slice()).unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (4, 3));
+    }
+}
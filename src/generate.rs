@@ -1,10 +1,11 @@
 use crate::{
-    ffi, Bitmap, ErrorCorrectionConfig, GeneratorConfig, Gray, MsdfGeneratorConfig, Rgb, Rgba,
-    Shape, Vector2,
+    ffi, Bitmap, ErrorCorrectionConfig, FieldKind, GeneratorConfig, Gray, MsdfGeneratorConfig, Rgb,
+    Rgba, Shape, Vector2,
 };
 
 /// Framing options
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Framing<T> {
     pub projection: Projection<T>,
@@ -64,6 +65,7 @@ impl<T> Framing<T> {
 
 /// Projection
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Projection<T> {
     pub scale: Vector2<T>,
@@ -89,7 +91,76 @@ impl Projection<f64> {
     }
 }
 
+/// Distance field generation helper trait
+///
+/// Generates true signed distance field for [`Gray`], multi-channel signed distance field for [`Rgb`]
+/// and multi-channel signed distance field with true distance in the alpha channel for [`Rgba`] pixels.
+pub trait FieldGeneration: Sized {
+    /// Kind of generated distance field
+    const KIND: FieldKind;
+
+    /// Name of distance field type as used by msdf-atlas-gen
+    const FIELD_TYPE: &'static str = Self::KIND.name();
+
+    /// Generates distance field of shape into bitmap
+    fn generate_field(
+        bitmap: &mut Bitmap<Self>,
+        shape: &Shape,
+        framing: &Framing<f64>,
+        config: &MsdfGeneratorConfig,
+    );
+}
+
+impl FieldGeneration for Gray<f32> {
+    const KIND: FieldKind = FieldKind::Sdf;
+
+    fn generate_field(
+        bitmap: &mut Bitmap<Self>,
+        shape: &Shape,
+        framing: &Framing<f64>,
+        config: &MsdfGeneratorConfig,
+    ) {
+        shape.generate_sdf(bitmap, framing, config);
+    }
+}
+
+impl FieldGeneration for Rgb<f32> {
+    const KIND: FieldKind = FieldKind::Msdf;
+
+    fn generate_field(
+        bitmap: &mut Bitmap<Self>,
+        shape: &Shape,
+        framing: &Framing<f64>,
+        config: &MsdfGeneratorConfig,
+    ) {
+        shape.generate_msdf(bitmap, framing, config);
+    }
+}
+
+impl FieldGeneration for Rgba<f32> {
+    const KIND: FieldKind = FieldKind::Mtsdf;
+
+    fn generate_field(
+        bitmap: &mut Bitmap<Self>,
+        shape: &Shape,
+        framing: &Framing<f64>,
+        config: &MsdfGeneratorConfig,
+    ) {
+        shape.generate_mtsdf(bitmap, framing, config);
+    }
+}
+
 impl Shape {
+    /// Generate distance field of kind which corresponds to the pixel type
+    pub fn generate_field<T: FieldGeneration>(
+        &self,
+        mut output: impl AsMut<Bitmap<T>>,
+        framing: impl AsRef<Framing<f64>>,
+        config: impl AsRef<MsdfGeneratorConfig>,
+    ) {
+        T::generate_field(output.as_mut(), self, framing.as_ref(), config.as_ref());
+    }
+
     /// Generate signed distance field for shape
     ///
     /// When interpreting pixel values,-1 in the resulting bitmap represents
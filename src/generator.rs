@@ -0,0 +1,455 @@
+use crate::{
+    Bitmap, DistanceField, FieldGeneration, FieldKind, FillRule, Framing, Gray,
+    MsdfErrorCorrection, MsdfGeneratorConfig, Range, Rgb, Rgba, Shape, SignCorrection,
+};
+use bytemuck::Pod;
+use core::fmt;
+
+/// Edge coloring strategy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeColoring {
+    /// Keep colors of edges as is
+    Keep,
+    /// Color edges using [`Shape::edge_coloring_simple`]
+    Simple {
+        /// Maximum angle (in radians) to be considered a corner
+        angle_threshold: f64,
+        /// Seed of coloring
+        seed: u64,
+    },
+}
+
+impl Default for EdgeColoring {
+    fn default() -> Self {
+        Self::Simple {
+            angle_threshold: 3.0,
+            seed: 0,
+        }
+    }
+}
+
+/// Error of distance field generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratorError {
+    /// Shape has unclosed contours
+    InvalidShape,
+    /// Bitmap is too small to fit the distance field range
+    FrameTooSmall,
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidShape => "invalid shape".fmt(f),
+            Self::FrameTooSmall => "frame too small to fit range".fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for GeneratorError {}
+
+/// Result of [`Generator`] with the generated output and framing which was used to generate it
+pub type GeneratorResult<T> = Result<(T, Framing<f64>), GeneratorError>;
+
+/// Output of [`Generator`]
+///
+/// The kind of distance field is determined by pixel type like for [`FieldGeneration`].
+pub trait GeneratorOutput: FieldGeneration + SignCorrection + Pod {
+    /// Generates distance field and applies corrections to it
+    fn generate_output(
+        bitmap: &mut Bitmap<Self>,
+        shape: &Shape,
+        framing: &Framing<f64>,
+        generator: &Generator,
+    );
+}
+
+impl GeneratorOutput for Gray<f32> {
+    fn generate_output(
+        bitmap: &mut Bitmap<Self>,
+        shape: &Shape,
+        framing: &Framing<f64>,
+        generator: &Generator,
+    ) {
+        if generator.pseudo_distance {
+            shape.generate_pseudo_sdf(&mut *bitmap, framing, generator.config);
+        } else {
+            shape.generate_sdf(&mut *bitmap, framing, generator.config);
+        }
+        shape.correct_sign(bitmap, framing, generator.fill_rule);
+    }
+}
+
+fn generate_multi_channel<T: FieldGeneration + SignCorrection + MsdfErrorCorrection>(
+    bitmap: &mut Bitmap<T>,
+    shape: &Shape,
+    framing: &Framing<f64>,
+    generator: &Generator,
+) {
+    shape.generate_field(&mut *bitmap, framing, generator.config);
+    shape.correct_sign(&mut *bitmap, framing, generator.fill_rule);
+    shape.correct_msdf_error(bitmap, framing, generator.config);
+}
+
+impl GeneratorOutput for Rgb<f32> {
+    fn generate_output(
+        bitmap: &mut Bitmap<Self>,
+        shape: &Shape,
+        framing: &Framing<f64>,
+        generator: &Generator,
+    ) {
+        generate_multi_channel(bitmap, shape, framing, generator);
+    }
+}
+
+impl GeneratorOutput for Rgba<f32> {
+    fn generate_output(
+        bitmap: &mut Bitmap<Self>,
+        shape: &Shape,
+        framing: &Framing<f64>,
+        generator: &Generator,
+    ) {
+        generate_multi_channel(bitmap, shape, framing, generator);
+    }
+}
+
+/// Distance field generator
+///
+/// Runs the whole pipeline: validation, normalization, edge coloring, framing,
+/// generation, sign and error correction, and flipping of output.
+///
+/// ```no_run
+/// use msdfgen::{Generator, Range, Rgb, Shape};
+///
+/// let mut shape = Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z").unwrap();
+///
+/// let (bitmap, framing) = Generator::default()
+///     .with_size(32, 32)
+///     .with_range(Range::Px(4.0))
+///     .with_flip_y(true)
+///     .generate::<Rgb<f32>>(&mut shape)
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy)]
+pub struct Generator {
+    width: u32,
+    height: u32,
+    range: Range<f64>,
+    coloring: EdgeColoring,
+    fill_rule: FillRule,
+    pseudo_distance: bool,
+    flip_y: bool,
+    config: MsdfGeneratorConfig,
+}
+
+impl AsRef<Generator> for Generator {
+    fn as_ref(&self) -> &Generator {
+        self
+    }
+}
+
+impl AsMut<Generator> for Generator {
+    fn as_mut(&mut self) -> &mut Generator {
+        self
+    }
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            width: 32,
+            height: 32,
+            range: Range::Px(4.0),
+            coloring: EdgeColoring::default(),
+            fill_rule: FillRule::default(),
+            pseudo_distance: false,
+            flip_y: false,
+            config: MsdfGeneratorConfig::default(),
+        }
+    }
+}
+
+impl Generator {
+    /// Get bitmap size (in pixels)
+    #[inline(always)]
+    pub fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Set bitmap size (in pixels)
+    #[inline(always)]
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Configure bitmap size (in pixels)
+    #[inline(always)]
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.set_size(width, height);
+        self
+    }
+
+    /// Get distance field range
+    #[inline(always)]
+    pub fn get_range(&self) -> Range<f64> {
+        self.range
+    }
+
+    /// Set distance field range
+    #[inline(always)]
+    pub fn set_range(&mut self, range: Range<f64>) {
+        self.range = range;
+    }
+
+    /// Configure distance field range
+    #[inline(always)]
+    pub fn with_range(mut self, range: Range<f64>) -> Self {
+        self.set_range(range);
+        self
+    }
+
+    /// Get edge coloring strategy
+    #[inline(always)]
+    pub fn get_coloring(&self) -> EdgeColoring {
+        self.coloring
+    }
+
+    /// Set edge coloring strategy
+    #[inline(always)]
+    pub fn set_coloring(&mut self, coloring: EdgeColoring) {
+        self.coloring = coloring;
+    }
+
+    /// Configure edge coloring strategy
+    #[inline(always)]
+    pub fn with_coloring(mut self, coloring: EdgeColoring) -> Self {
+        self.set_coloring(coloring);
+        self
+    }
+
+    /// Get fill rule used for sign correction
+    #[inline(always)]
+    pub fn get_fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Set fill rule used for sign correction
+    #[inline(always)]
+    pub fn set_fill_rule(&mut self, fill_rule: FillRule) {
+        self.fill_rule = fill_rule;
+    }
+
+    /// Configure fill rule used for sign correction
+    #[inline(always)]
+    pub fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.set_fill_rule(fill_rule);
+        self
+    }
+
+    /// Get pseudo distance usage
+    #[inline(always)]
+    pub fn get_pseudo_distance(&self) -> bool {
+        self.pseudo_distance
+    }
+
+    /// Set pseudo distance usage
+    ///
+    /// When enabled single-channel fields are generated as pseudo signed distance fields.
+    #[inline(always)]
+    pub fn set_pseudo_distance(&mut self, pseudo_distance: bool) {
+        self.pseudo_distance = pseudo_distance;
+    }
+
+    /// Configure pseudo distance usage
+    #[inline(always)]
+    pub fn with_pseudo_distance(mut self, pseudo_distance: bool) -> Self {
+        self.set_pseudo_distance(pseudo_distance);
+        self
+    }
+
+    /// Get output flipping
+    #[inline(always)]
+    pub fn get_flip_y(&self) -> bool {
+        self.flip_y
+    }
+
+    /// Set output flipping
+    ///
+    /// Generated bitmaps are y-up. Flipping makes the first row the top one like image formats expect.
+    #[inline(always)]
+    pub fn set_flip_y(&mut self, flip_y: bool) {
+        self.flip_y = flip_y;
+    }
+
+    /// Configure output flipping
+    #[inline(always)]
+    pub fn with_flip_y(mut self, flip_y: bool) -> Self {
+        self.set_flip_y(flip_y);
+        self
+    }
+
+    /// Get generator config
+    #[inline(always)]
+    pub fn get_config(&self) -> &MsdfGeneratorConfig {
+        &self.config
+    }
+
+    /// Set generator config
+    ///
+    /// The config includes the overlap support and error correction options.
+    #[inline(always)]
+    pub fn set_config(&mut self, config: MsdfGeneratorConfig) {
+        self.config = config;
+    }
+
+    /// Configure generator config
+    #[inline(always)]
+    pub fn with_config(mut self, config: MsdfGeneratorConfig) -> Self {
+        self.set_config(config);
+        self
+    }
+
+    /// Generates distance field of shape
+    ///
+    /// The shape is normalized and colored in place.
+    ///
+    /// Returns the bitmap with the framing which was used to generate it.
+    pub fn generate<T: GeneratorOutput>(&self, shape: &mut Shape) -> GeneratorResult<Bitmap<T>> {
+        let framing = self.prepare(shape)?;
+        Ok((self.output(shape, &framing), framing))
+    }
+
+    /// Generates distance field of specified kind
+    ///
+    /// Like [`Generator::generate`] but the kind of distance field is selected at runtime.
+    pub fn generate_field(
+        &self,
+        kind: FieldKind,
+        shape: &mut Shape,
+    ) -> GeneratorResult<DistanceField> {
+        let framing = self.prepare(shape)?;
+
+        let field = match kind {
+            FieldKind::Sdf => {
+                DistanceField::Sdf(self.with_pseudo_distance(false).output(shape, &framing))
+            }
+            FieldKind::PseudoSdf => {
+                DistanceField::PseudoSdf(self.with_pseudo_distance(true).output(shape, &framing))
+            }
+            FieldKind::Msdf => DistanceField::Msdf(self.output(shape, &framing)),
+            FieldKind::Mtsdf => DistanceField::Mtsdf(self.output(shape, &framing)),
+        };
+
+        Ok((field, framing))
+    }
+
+    /// Validates, normalizes and colors shape and computes framing
+    fn prepare(&self, shape: &mut Shape) -> Result<Framing<f64>, GeneratorError> {
+        if !shape.validate() {
+            return Err(GeneratorError::InvalidShape);
+        }
+
+        shape.normalize();
+
+        if let EdgeColoring::Simple {
+            angle_threshold,
+            seed,
+        } = self.coloring
+        {
+            shape.edge_coloring_simple(angle_threshold, seed);
+        }
+
+        shape
+            .get_bound()
+            .autoframe(self.width, self.height, self.range, None)
+            .ok_or(GeneratorError::FrameTooSmall)
+    }
+
+    fn output<T: GeneratorOutput>(&self, shape: &Shape, framing: &Framing<f64>) -> Bitmap<T> {
+        let mut bitmap = Bitmap::new(self.width, self.height);
+        T::generate_output(&mut bitmap, shape, framing, self);
+
+        if self.flip_y {
+            bitmap.flip_y();
+        }
+
+        bitmap
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Contour, EdgeColor, EdgeHolder};
+
+    #[test]
+    fn pipeline() {
+        let path = "M1 1 L1 9 Q5 12 9 9 L9 1 Z";
+
+        let mut shape = Shape::from_svg_path_data(path).unwrap();
+        let (bitmap, framing) = Generator::default()
+            .with_size(16, 16)
+            .with_flip_y(true)
+            .generate::<Rgb<f32>>(&mut shape)
+            .unwrap();
+
+        let mut expected_shape = Shape::from_svg_path_data(path).unwrap();
+        expected_shape.normalize();
+        expected_shape.edge_coloring_simple(3.0, 0);
+        let expected_framing = expected_shape
+            .get_bound()
+            .autoframe(16, 16, Range::Px(4.0), None)
+            .unwrap();
+        let config = MsdfGeneratorConfig::default();
+        let mut expected = Bitmap::<Rgb<f32>>::new(16, 16);
+        expected_shape.generate_msdf(&mut expected, expected_framing, config);
+        expected_shape.correct_sign(&mut expected, expected_framing, FillRule::NonZero);
+        expected_shape.correct_msdf_error(&mut expected, expected_framing, config);
+        expected.flip_y();
+
+        assert_eq!(framing, expected_framing);
+        assert_eq!(bitmap.pixels(), expected.pixels());
+        assert_eq!(shape.to_description(), expected_shape.to_description());
+
+        let (bitmap, _) = Generator::default()
+            .with_pseudo_distance(true)
+            .generate::<Gray<f32>>(&mut shape)
+            .unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (32, 32));
+
+        let mut shape = Shape::from_svg_path_data(path).unwrap();
+        let (field, field_framing) = Generator::default()
+            .with_size(16, 16)
+            .with_flip_y(true)
+            .generate_field(FieldKind::Msdf, &mut shape)
+            .unwrap();
+        assert_eq!(field_framing, framing);
+        assert_eq!(field.raw_pixels(), expected.raw_pixels());
+    }
+
+    #[test]
+    fn errors() {
+        let mut shape = Shape::from_svg_path_data("M0 0 L0 10 L10 10 Z").unwrap();
+        assert_eq!(
+            Generator::default()
+                .with_size(4, 4)
+                .generate::<Rgba<f32>>(&mut shape)
+                .err(),
+            Some(GeneratorError::FrameTooSmall)
+        );
+
+        let mut contour = Contour::default();
+        contour.add_edge(&EdgeHolder::new_linear(
+            (0.0, 0.0),
+            (1.0, 1.0),
+            EdgeColor::White,
+        ));
+        let mut shape = Shape::default();
+        shape.add_contour(&contour);
+        assert_eq!(
+            Generator::default().generate::<Gray<f32>>(&mut shape).err(),
+            Some(GeneratorError::InvalidShape)
+        );
+    }
+}
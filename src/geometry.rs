@@ -0,0 +1,396 @@
+use crate::{Contour, EdgeHolder, Point2, SegmentKind, ShapeBuilder, Vector2};
+
+/// Plain bezier curve used by geometry algorithms
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Bezier {
+    Linear([Point2<f64>; 2]),
+    Quadratic([Point2<f64>; 3]),
+    Cubic([Point2<f64>; 4]),
+}
+
+impl Bezier {
+    pub fn from_edge(edge: &EdgeHolder) -> Self {
+        let start = *edge.start_point();
+        let end = *edge.end_point();
+        match edge.segment_kind() {
+            SegmentKind::Linear => Self::Linear([start, end]),
+            SegmentKind::Quadratic => {
+                Self::Quadratic([start, *edge.control_point(0).unwrap(), end])
+            }
+            SegmentKind::Cubic => Self::Cubic([
+                start,
+                *edge.control_point(0).unwrap(),
+                *edge.control_point(1).unwrap(),
+                end,
+            ]),
+        }
+    }
+
+    pub fn points(&self) -> &[Point2<f64>] {
+        match self {
+            Self::Linear(points) => points,
+            Self::Quadratic(points) => points,
+            Self::Cubic(points) => points,
+        }
+    }
+
+    pub fn start(&self) -> Point2<f64> {
+        self.points()[0]
+    }
+
+    pub fn end(&self) -> Point2<f64> {
+        *self.points().last().unwrap()
+    }
+
+    /// Checks that all points of curve coincide
+    pub fn is_degenerate(&self) -> bool {
+        let start = self.start();
+        self.points().iter().all(|point| *point == start)
+    }
+
+    pub fn reversed(&self) -> Self {
+        match *self {
+            Self::Linear([p0, p1]) => Self::Linear([p1, p0]),
+            Self::Quadratic([p0, p1, p2]) => Self::Quadratic([p2, p1, p0]),
+            Self::Cubic([p0, p1, p2, p3]) => Self::Cubic([p3, p2, p1, p0]),
+        }
+    }
+
+    /// Gets tangent direction at the start (not normalized)
+    ///
+    /// Coinciding control points are skipped so the direction is non-zero for non-degenerate curves.
+    pub fn start_direction(&self) -> Vector2<f64> {
+        let start = self.start();
+        self.points()[1..]
+            .iter()
+            .map(|point| *point - start)
+            .find(|direction| *direction != Vector2::new(0.0, 0.0))
+            .unwrap_or_default()
+    }
+
+    /// Gets tangent direction at the end (not normalized)
+    pub fn end_direction(&self) -> Vector2<f64> {
+        -self.reversed().start_direction()
+    }
+
+    /// Gets point of curve at parameter
+    pub fn point(&self, t: f64) -> Point2<f64> {
+        self.split(t).0.end()
+    }
+
+    /// Gets tangent direction of curve at parameter (not normalized)
+    pub fn direction(&self, t: f64) -> Vector2<f64> {
+        let direction = cubic_derivative(&self.to_cubic(), t);
+        if direction == Vector2::default() {
+            self.end() - self.start()
+        } else {
+            direction
+        }
+    }
+
+    /// Splits curve at parameter into two curves of the same kind
+    pub fn split(&self, t: f64) -> (Self, Self) {
+        match *self {
+            Self::Linear(points) => {
+                let (a, b) = split_points(points, t);
+                (Self::Linear(a), Self::Linear(b))
+            }
+            Self::Quadratic(points) => {
+                let (a, b) = split_points(points, t);
+                (Self::Quadratic(a), Self::Quadratic(b))
+            }
+            Self::Cubic(points) => {
+                let (a, b) = split_points(points, t);
+                (Self::Cubic(a), Self::Cubic(b))
+            }
+        }
+    }
+
+    /// Gets the part of curve from `t0` to `t1`
+    pub fn segment(&self, t0: f64, t1: f64) -> Self {
+        let head = if t1 < 1.0 { self.split(t1).0 } else { *self };
+        if t0 > 0.0 && t1 > 0.0 {
+            head.split(t0 / t1).1
+        } else {
+            head
+        }
+    }
+
+    /// Gets bounding box of control points as minimum and maximum corners
+    pub fn control_bound(&self) -> (Point2<f64>, Point2<f64>) {
+        let start = self.start();
+        self.points()
+            .iter()
+            .fold((start, start), |(min, max), point| {
+                (
+                    Point2::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point2::new(max.x.max(point.x), max.y.max(point.y)),
+                )
+            })
+    }
+
+    /// Sets start and end points of curve
+    pub fn set_ends(&mut self, start: Point2<f64>, end: Point2<f64>) {
+        let points: &mut [Point2<f64>] = match self {
+            Self::Linear(points) => points,
+            Self::Quadratic(points) => points,
+            Self::Cubic(points) => points,
+        };
+        points[0] = start;
+        *points.last_mut().unwrap() = end;
+    }
+
+    /// Gets total sign of intersections of curve with scanline at the left of point
+    ///
+    /// The intersections are counted like by [`crate::Scanline::sum_intersections`].
+    pub fn scanline_sum(&self, point: Point2<f64>) -> i32 {
+        let cubic = self.to_cubic();
+        let [y0, y1, y2, y3] = cubic.map(|point| point.y);
+
+        // split curve at extremums so that each part is monotonic in y
+        let (d0, d1, d2) = (y1 - y0, y2 - y1, y3 - y2);
+        let mut params = [0.0; 4];
+        let mut count = 1;
+        for t in solve_quadratic(d0 - 2.0 * d1 + d2, 2.0 * (d1 - d0), d0) {
+            if t > params[count - 1] && t < 1.0 {
+                params[count] = t;
+                count += 1;
+            }
+        }
+        params[count] = 1.0;
+        count += 1;
+
+        let mut sum = 0;
+        for range in params[..count].windows(2) {
+            let (t0, t1) = (range[0], range[1]);
+            let (start, end) = (cubic_point(&cubic, t0).y, cubic_point(&cubic, t1).y);
+            if !(start <= point.y && point.y < end || end <= point.y && point.y < start) {
+                continue;
+            }
+
+            // bisection of monotonic part
+            let (mut low, mut high) = (t0, t1);
+            for _ in 0..64 {
+                let middle = (low + high) * 0.5;
+                if (cubic_point(&cubic, middle).y < point.y) == (start < end) {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+
+            if cubic_point(&cubic, (low + high) * 0.5).x <= point.x {
+                sum += if start < end { 1 } else { -1 };
+            }
+        }
+        sum
+    }
+
+    /// Adds curve to builder which current point is the start of curve
+    pub fn add_to(&self, builder: &mut ShapeBuilder) {
+        match *self {
+            Self::Linear([_, p1]) => builder.line_to(p1),
+            Self::Quadratic([_, p1, p2]) => builder.quad_to(p1, p2),
+            Self::Cubic([_, p1, p2, p3]) => builder.curve_to(p1, p2, p3),
+        };
+    }
+
+    /// Converts curve to cubic form
+    pub fn to_cubic(self) -> [Point2<f64>; 4] {
+        match self {
+            Self::Linear([p0, p1]) => [p0, mix(p0, p1, 1.0 / 3.0), mix(p0, p1, 2.0 / 3.0), p1],
+            Self::Quadratic([p0, p1, p2]) => {
+                [p0, mix(p0, p1, 2.0 / 3.0), mix(p2, p1, 2.0 / 3.0), p2]
+            }
+            Self::Cubic(points) => points,
+        }
+    }
+}
+
+/// Gets non-degenerate curves of contour
+pub(crate) fn contour_curves(contour: &Contour) -> Vec<Bezier> {
+    contour
+        .edges()
+        .iter()
+        .map(Bezier::from_edge)
+        .filter(|curve| !curve.is_degenerate())
+        .collect()
+}
+
+/// Splits control points of bezier curve using de Casteljau's algorithm
+fn split_points<const N: usize>(
+    points: [Point2<f64>; N],
+    t: f64,
+) -> ([Point2<f64>; N], [Point2<f64>; N]) {
+    let mut head = points;
+    let mut tail = points;
+    let mut work = points;
+    for level in 0..N {
+        head[level] = work[0];
+        tail[N - 1 - level] = work[N - 1 - level];
+        for index in 0..N - 1 - level {
+            work[index] = mix(work[index], work[index + 1], t);
+        }
+    }
+    (head, tail)
+}
+
+/// Gets total sign of intersections of curves with scanline at the left of point
+pub(crate) fn scanline_sum(curves: &[Bezier], point: Point2<f64>) -> i32 {
+    curves.iter().map(|curve| curve.scanline_sum(point)).sum()
+}
+
+/// Finds real roots of `a * x^2 + b * x + c` in ascending order
+fn solve_quadratic(a: f64, b: f64, c: f64) -> impl Iterator<Item = f64> {
+    let mut roots = [f64::NAN; 2];
+    if a.abs() < 1e-12 * (b.abs() + c.abs()) || a == 0.0 {
+        if b != 0.0 {
+            roots[0] = -c / b;
+        }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant >= 0.0 {
+            let root = discriminant.sqrt();
+            let (x0, x1) = ((-b - root) / (2.0 * a), (-b + root) / (2.0 * a));
+            roots = [x0.min(x1), x0.max(x1)];
+        }
+    }
+    roots.into_iter().filter(|root| !root.is_nan())
+}
+
+/// Gets point of cubic curve at parameter
+pub(crate) fn cubic_point(points: &[Point2<f64>; 4], t: f64) -> Point2<f64> {
+    let [p0, p1, p2, p3] = *points;
+    let a = mix(mix(p0, p1, t), mix(p1, p2, t), t);
+    let b = mix(mix(p1, p2, t), mix(p2, p3, t), t);
+    mix(a, b, t)
+}
+
+/// Gets derivative of cubic curve
+pub(crate) fn cubic_derivative(points: &[Point2<f64>; 4], t: f64) -> Vector2<f64> {
+    let [p0, p1, p2, p3] = *points;
+    let a = mix(p1 - p0, p2 - p1, t);
+    let b = mix(p2 - p1, p3 - p2, t);
+    mix(a, b, t) * 3.0
+}
+
+/// Splits cubic curve into the part from `t0` to `t1`
+pub(crate) fn cubic_segment(points: &[Point2<f64>; 4], t0: f64, t1: f64) -> [Point2<f64>; 4] {
+    let scale = (t1 - t0) / 3.0;
+    let start = cubic_point(points, t0);
+    let end = cubic_point(points, t1);
+    [
+        start,
+        start + cubic_derivative(points, t0) * scale,
+        end - cubic_derivative(points, t1) * scale,
+        end,
+    ]
+}
+
+/// Linear interpolation between points
+pub(crate) fn mix(a: Point2<f64>, b: Point2<f64>, t: f64) -> Point2<f64> {
+    a * (1.0 - t) + b * t
+}
+
+/// Finds parameters of intersections of curves
+pub(crate) fn intersect(
+    a: &Bezier,
+    b: &Bezier,
+    tolerance: f64,
+    a_params: &mut Vec<f64>,
+    b_params: &mut Vec<f64>,
+) {
+    if let (Bezier::Linear(a), Bezier::Linear(b)) = (a, b) {
+        intersect_lines(*a, *b, tolerance, a_params, b_params);
+        return;
+    }
+
+    // limits the number of steps for coinciding curves
+    let mut budget = 1 << 14;
+    let mut params = Vec::new();
+    subdivide(
+        (a, 0.0, 1.0),
+        (b, 0.0, 1.0),
+        tolerance,
+        &mut budget,
+        &mut params,
+    );
+
+    for (a_param, b_param) in params {
+        a_params.push(a_param);
+        b_params.push(b_param);
+    }
+}
+
+fn intersect_lines(
+    a: [Point2<f64>; 2],
+    b: [Point2<f64>; 2],
+    tolerance: f64,
+    a_params: &mut Vec<f64>,
+    b_params: &mut Vec<f64>,
+) {
+    let da = a[1] - a[0];
+    let db = b[1] - b[0];
+    let denom = da.cross(&db);
+    let offset = b[0] - a[0];
+
+    if denom.abs() > 1e-12 * da.length() * db.length() {
+        let s = offset.cross(&db) / denom;
+        let t = offset.cross(&da) / denom;
+        let range = -1e-9..=1.0 + 1e-9;
+        if range.contains(&s) && range.contains(&t) {
+            a_params.push(s.clamp(0.0, 1.0));
+            b_params.push(t.clamp(0.0, 1.0));
+        }
+    } else if offset.cross(&da).abs() <= tolerance * da.length() {
+        // overlapping lines are split at ends of each other
+        let project = |line: [Point2<f64>; 2], point: Point2<f64>| {
+            let direction = line[1] - line[0];
+            (point - line[0]).dot(&direction) / direction.dot(&direction)
+        };
+        a_params.extend(b.iter().map(|point| project(a, *point)));
+        b_params.extend(a.iter().map(|point| project(b, *point)));
+    }
+}
+
+/// Finds intersections by recursive subdivision of curves
+fn subdivide(
+    a: (&Bezier, f64, f64),
+    b: (&Bezier, f64, f64),
+    tolerance: f64,
+    budget: &mut usize,
+    params: &mut Vec<(f64, f64)>,
+) {
+    let (a_min, a_max) = a.0.control_bound();
+    let (b_min, b_max) = b.0.control_bound();
+
+    if a_min.x > b_max.x + tolerance
+        || b_min.x > a_max.x + tolerance
+        || a_min.y > b_max.y + tolerance
+        || b_min.y > a_max.y + tolerance
+        || *budget == 0
+    {
+        return;
+    }
+    *budget -= 1;
+
+    let a_size = (a_max - a_min).length();
+    let b_size = (b_max - b_min).length();
+
+    if a_size <= tolerance && b_size <= tolerance {
+        params.push(((a.1 + a.2) * 0.5, (b.1 + b.2) * 0.5));
+        return;
+    }
+
+    if a_size >= b_size {
+        let (head, tail) = a.0.split(0.5);
+        let middle = (a.1 + a.2) * 0.5;
+        subdivide((&head, a.1, middle), b, tolerance, budget, params);
+        subdivide((&tail, middle, a.2), b, tolerance, budget, params);
+    } else {
+        let (head, tail) = b.0.split(0.5);
+        let middle = (b.1 + b.2) * 0.5;
+        subdivide(a, (&head, b.1, middle), tolerance, budget, params);
+        subdivide(a, (&tail, middle, b.2), tolerance, budget, params);
+    }
+}
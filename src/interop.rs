@@ -7,7 +7,28 @@ mod font;
 #[cfg(feature = "freetype-rs")]
 mod freetype_rs;
 
-use crate::Shape;
+#[cfg(feature = "freetype-rs")]
+pub use self::freetype_rs::*;
+
+#[cfg(feature = "usvg")]
+mod usvg;
+
+#[cfg(feature = "usvg")]
+pub use self::usvg::*;
+
+#[cfg(feature = "lyon")]
+mod lyon;
+
+#[cfg(feature = "kurbo")]
+mod kurbo;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "rayon")]
+mod rayon;
+
+use crate::{Rgba, Shape};
 
 /// Extensions for font objects
 pub trait FontExt {
@@ -16,3 +37,93 @@ pub trait FontExt {
     /// Crates a shape for specific glyph of font
     fn glyph_shape(&self, glyph: Self::Glyph) -> Option<Shape>;
 }
+
+/// Font-wide vertical metrics
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FontMetrics {
+    /// Distance from baseline to the top of font
+    pub ascender: f64,
+    /// Distance from baseline to the bottom of font (usually negative)
+    pub descender: f64,
+    /// Distance between baselines of consecutive lines
+    pub line_height: f64,
+    /// Vertical position of underline
+    pub underline_y: f64,
+    /// Thickness of underline
+    pub underline_thickness: f64,
+}
+
+impl FontMetrics {
+    /// Scale all metrics by factor
+    pub fn scaled(&self, factor: f64) -> Self {
+        Self {
+            ascender: self.ascender * factor,
+            descender: self.descender * factor,
+            line_height: self.line_height * factor,
+            underline_y: self.underline_y * factor,
+            underline_thickness: self.underline_thickness * factor,
+        }
+    }
+}
+
+/// Extensions for font objects which provides character mapping and glyph metrics
+///
+/// All metrics are given in the same units as the shapes created by [`FontExt::glyph_shape`].
+pub trait FontMetricsExt: FontExt {
+    /// Gets the number of font units per em
+    fn units_per_em(&self) -> f64;
+
+    /// Finds the glyph for specific character
+    fn glyph_index(&self, chr: char) -> Option<Self::Glyph>;
+
+    /// Gets the horizontal advance of specific glyph
+    fn glyph_advance(&self, glyph: Self::Glyph) -> Option<f64>;
+
+    /// Gets the font-wide vertical metrics
+    fn font_metrics(&self) -> FontMetrics;
+
+    /// Gets the horizontal kerning adjustment between pair of glyphs
+    ///
+    /// Returns `None` when font has no kerning for the pair.
+    /// Only the legacy `kern` table is read, the pair adjustments of `GPOS` table
+    /// (which most modern fonts use instead) are not supported.
+    fn glyph_kerning(&self, left: Self::Glyph, right: Self::Glyph) -> Option<f64>;
+
+    /// Gets the non-zero kerning adjustments between glyphs of set
+    ///
+    /// Returns `(left, right, kerning)` tuples where `left` and `right` are indices of glyphs
+    /// ordered by `left` then by `right`. The default implementation queries
+    /// [`FontMetricsExt::glyph_kerning`] for every ordered pair of glyphs,
+    /// so font objects which can enumerate kerning pairs should override it.
+    fn glyphs_kerning(&self, glyphs: &[Self::Glyph]) -> Vec<(usize, usize, f64)>
+    where
+        Self::Glyph: Copy,
+    {
+        let mut kerning = Vec::new();
+        for (left, left_glyph) in glyphs.iter().enumerate() {
+            for (right, right_glyph) in glyphs.iter().enumerate() {
+                match self.glyph_kerning(*left_glyph, *right_glyph) {
+                    Some(advance) if advance != 0.0 => kerning.push((left, right, advance)),
+                    _ => {}
+                }
+            }
+        }
+        kerning
+    }
+}
+
+/// Extensions for color font objects
+pub trait ColorFontExt: FontExt {
+    /// Creates colored shapes for layers of specific color glyph
+    ///
+    /// The `palette` selects color palette of font and the `foreground` color is used for layers
+    /// which should be painted using text color.
+    ///
+    /// Returns `None` when glyph has no color layers.
+    fn glyph_color_layers(
+        &self,
+        glyph: Self::Glyph,
+        palette: u16,
+        foreground: Rgba<u8>,
+    ) -> Option<Vec<(Shape, Rgba<u8>)>>;
+}
@@ -1,25 +1,169 @@
-use crate::{EdgeColor, EdgeHolder, FontExt, Point2, Shape};
+use crate::{EdgeColor, EdgeHolder, FontExt, FontMetrics, FontMetricsExt, Point2, Shape, Vector2};
+use freetype::face::{KerningMode, LoadFlag};
 
-impl FontExt for freetype::face::Face {
-    type Glyph = u32;
+/// Units of glyph outline coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FreetypeUnits {
+    /// Coordinates as loaded by FreeType
+    ///
+    /// These are font units when glyph loaded with [`LoadFlag::NO_SCALE`] and 26.6 fixed-point
+    /// values (1/64 of pixel) otherwise.
+    #[default]
+    Native,
+
+    /// Coordinates normalized to the em square
+    Em,
+}
+
+/// FreeType glyph loading config
+#[derive(Debug, Clone, Copy)]
+pub struct FreetypeConfig {
+    load_flags: LoadFlag,
+    units: FreetypeUnits,
+    embolden: f64,
+}
+
+impl AsRef<FreetypeConfig> for FreetypeConfig {
+    fn as_ref(&self) -> &FreetypeConfig {
+        self
+    }
+}
+
+impl AsMut<FreetypeConfig> for FreetypeConfig {
+    fn as_mut(&mut self) -> &mut FreetypeConfig {
+        self
+    }
+}
+
+impl Default for FreetypeConfig {
+    fn default() -> Self {
+        Self {
+            load_flags: LoadFlag::NO_SCALE,
+            units: FreetypeUnits::default(),
+            embolden: 0.0,
+        }
+    }
+}
+
+impl FreetypeConfig {
+    /// Get glyph load flags
+    #[inline(always)]
+    pub fn get_load_flags(&self) -> LoadFlag {
+        self.load_flags
+    }
+
+    /// Set glyph load flags
+    #[inline(always)]
+    pub fn set_load_flags(&mut self, load_flags: LoadFlag) {
+        self.load_flags = load_flags;
+    }
+
+    /// Configure glyph load flags
+    #[inline(always)]
+    pub fn with_load_flags(mut self, load_flags: LoadFlag) -> Self {
+        self.set_load_flags(load_flags);
+        self
+    }
+
+    /// Get outline units
+    #[inline(always)]
+    pub fn get_units(&self) -> FreetypeUnits {
+        self.units
+    }
+
+    /// Set outline units
+    #[inline(always)]
+    pub fn set_units(&mut self, units: FreetypeUnits) {
+        self.units = units;
+    }
+
+    /// Configure outline units
+    #[inline(always)]
+    pub fn with_units(mut self, units: FreetypeUnits) -> Self {
+        self.set_units(units);
+        self
+    }
+
+    /// Get embolden strength
+    #[inline(always)]
+    pub fn get_embolden(&self) -> f64 {
+        self.embolden
+    }
+
+    /// Set embolden strength
+    ///
+    /// The strength is given in native outline units (see [`FreetypeUnits::Native`]).
+    /// The outline is emboldened using `FT_Outline_Embolden` before conversion when the strength is non-zero.
+    #[inline(always)]
+    pub fn set_embolden(&mut self, strength: f64) {
+        self.embolden = strength;
+    }
+
+    /// Configure embolden strength
+    #[inline(always)]
+    pub fn with_embolden(mut self, strength: f64) -> Self {
+        self.set_embolden(strength);
+        self
+    }
+}
+
+/// Extensions for FreeType font faces
+pub trait FreetypeFaceExt {
+    /// Crates a shape for specific glyph of font using config
+    fn glyph_shape_with(&self, glyph: u32, config: impl AsRef<FreetypeConfig>) -> Option<Shape>;
+}
+
+impl FreetypeFaceExt for freetype::face::Face {
+    fn glyph_shape_with(&self, glyph: u32, config: impl AsRef<FreetypeConfig>) -> Option<Shape> {
+        let config = config.as_ref();
+
+        self.load_glyph(glyph, config.load_flags).ok()?;
+
+        let slot = self.raw().glyph;
+
+        if config.embolden != 0.0 {
+            let error = unsafe {
+                freetype::ffi::FT_Outline_Embolden(
+                    &mut (*slot).outline,
+                    config.embolden.round() as _,
+                )
+            };
+            if error != 0 {
+                return None;
+            }
+        }
+
+        let scale = match config.units {
+            FreetypeUnits::Native => Vector2::new(1.0, 1.0),
+            FreetypeUnits::Em => {
+                let units_per_em = self.raw().units_per_EM as f64;
+                if config.load_flags.contains(LoadFlag::NO_SCALE) {
+                    Vector2::from(1.0 / units_per_em)
+                } else {
+                    let metrics = self.size_metrics()?;
+                    Vector2::new(
+                        65536.0 / (metrics.x_scale as f64 * units_per_em),
+                        65536.0 / (metrics.y_scale as f64 * units_per_em),
+                    )
+                }
+            }
+        };
+
+        let point = |p: &freetype::Vector| Point2::new(p.x as f64, p.y as f64) * scale;
 
-    fn glyph_shape(&self, glyph: Self::Glyph) -> Option<Shape> {
-        self.load_glyph(glyph, freetype::face::LoadFlag::NO_SCALE)
-            .ok()?;
         let glyph = self.glyph();
         let outline = glyph.outline()?;
 
         let mut shape = Shape::default();
 
         for contour in outline.contours_iter() {
-            let p = contour.start();
             let last_contour = shape.add_contour_mut();
-            let mut last_point = Point2::new(p.x as f64, p.y as f64);
+            let mut last_point = point(contour.start());
 
             for curve in contour {
                 match curve {
                     freetype::outline::Curve::Line(p) => {
-                        let point = Point2::new(p.x as f64, p.y as f64);
+                        let point = point(&p);
                         last_contour.add_edge(&EdgeHolder::new_linear(
                             last_point,
                             point,
@@ -28,8 +172,8 @@ impl FontExt for freetype::face::Face {
                         last_point = point;
                     }
                     freetype::outline::Curve::Bezier2(c, p) => {
-                        let cpoint = Point2::new(c.x as f64, c.y as f64);
-                        let point = Point2::new(p.x as f64, p.y as f64);
+                        let cpoint = point(&c);
+                        let point = point(&p);
                         last_contour.add_edge(&EdgeHolder::new_quadratic(
                             last_point,
                             cpoint,
@@ -39,9 +183,9 @@ impl FontExt for freetype::face::Face {
                         last_point = point;
                     }
                     freetype::outline::Curve::Bezier3(c1, c2, p) => {
-                        let c1point = Point2::new(c1.x as f64, c1.y as f64);
-                        let c2point = Point2::new(c2.x as f64, c2.y as f64);
-                        let point = Point2::new(p.x as f64, p.y as f64);
+                        let c1point = point(&c1);
+                        let c2point = point(&c2);
+                        let point = point(&p);
                         last_contour.add_edge(&EdgeHolder::new_cubic(
                             last_point,
                             c1point,
@@ -58,3 +202,93 @@ impl FontExt for freetype::face::Face {
         shape.into()
     }
 }
+
+impl FontExt for freetype::face::Face {
+    type Glyph = u32;
+
+    fn glyph_shape(&self, glyph: Self::Glyph) -> Option<Shape> {
+        self.glyph_shape_with(glyph, FreetypeConfig::default())
+    }
+}
+
+impl FontMetricsExt for freetype::face::Face {
+    fn units_per_em(&self) -> f64 {
+        self.raw().units_per_EM as _
+    }
+
+    fn glyph_index(&self, chr: char) -> Option<Self::Glyph> {
+        match self.get_char_index(chr as usize) {
+            0 => None,
+            glyph => Some(glyph),
+        }
+    }
+
+    fn glyph_advance(&self, glyph: Self::Glyph) -> Option<f64> {
+        self.load_glyph(glyph, LoadFlag::NO_SCALE).ok()?;
+        Some(self.glyph().advance().x as _)
+    }
+
+    fn font_metrics(&self) -> FontMetrics {
+        FontMetrics {
+            ascender: self.ascender() as _,
+            descender: self.descender() as _,
+            line_height: self.height() as _,
+            underline_y: self.underline_position() as _,
+            underline_thickness: self.underline_thickness() as _,
+        }
+    }
+
+    fn glyph_kerning(&self, left: Self::Glyph, right: Self::Glyph) -> Option<f64> {
+        if !self.has_kerning() {
+            return None;
+        }
+        match self
+            .get_kerning(left, right, KerningMode::KerningUnscaled)
+            .ok()?
+            .x
+        {
+            0 => None,
+            kerning => Some(kerning as _),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use freetype::Library;
+    use notosans::REGULAR_TTF;
+
+    #[test]
+    fn glyph_shape_em_units() {
+        let library = Library::init().unwrap();
+        let face = library.new_memory_face(REGULAR_TTF.to_vec(), 0).unwrap();
+        let glyph = face.get_char_index('H' as usize);
+
+        let config = FreetypeConfig::default().with_units(FreetypeUnits::Em);
+        let bound = face.glyph_shape_with(glyph, config).unwrap().get_bound();
+
+        assert!(bound.top > 0.5 && bound.top < 1.0);
+
+        face.set_pixel_sizes(0, 64).unwrap();
+
+        let config = config.with_load_flags(LoadFlag::NO_HINTING);
+        let scaled_bound = face.glyph_shape_with(glyph, config).unwrap().get_bound();
+
+        assert!((scaled_bound.top - bound.top).abs() < 0.01);
+    }
+
+    #[test]
+    fn glyph_shape_embolden() {
+        let library = Library::init().unwrap();
+        let face = library.new_memory_face(REGULAR_TTF.to_vec(), 0).unwrap();
+        let glyph = face.get_char_index('H' as usize);
+
+        let bound = face.glyph_shape(glyph).unwrap().get_bound();
+
+        let config = FreetypeConfig::default().with_embolden(40.0);
+        let bold_bound = face.glyph_shape_with(glyph, config).unwrap().get_bound();
+
+        assert!(bold_bound.width() > bound.width());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{SegmentKind, Shape, ShapeBuilder};
+use kurbo::{BezPath, PathEl};
+
+impl From<&BezPath> for Shape {
+    /// Creates shape from bezier path
+    ///
+    /// Each subpath becomes separate contour. Open subpaths are closed implicitly
+    /// like when path is filled.
+    fn from(path: &BezPath) -> Self {
+        let mut builder = ShapeBuilder::new();
+
+        for element in path.elements() {
+            match *element {
+                PathEl::MoveTo(point) => {
+                    builder.move_to((point.x, point.y));
+                }
+                PathEl::LineTo(point) => {
+                    builder.line_to((point.x, point.y));
+                }
+                PathEl::QuadTo(cpoint, point) => {
+                    builder.quad_to((cpoint.x, cpoint.y), (point.x, point.y));
+                }
+                PathEl::CurveTo(c1point, c2point, point) => {
+                    builder.curve_to(
+                        (c1point.x, c1point.y),
+                        (c2point.x, c2point.y),
+                        (point.x, point.y),
+                    );
+                }
+                PathEl::ClosePath => {
+                    builder.close();
+                }
+            }
+        }
+
+        builder.build()
+    }
+}
+
+impl Shape {
+    /// Converts shape into bezier path
+    ///
+    /// Each contour becomes closed subpath and each edge becomes single path element.
+    pub fn to_bez_path(&self) -> BezPath {
+        let mut path = BezPath::new();
+
+        for contour in self.contours() {
+            let edges = contour.edges();
+            let first = if let Some(first) = edges.first() {
+                first.start_point()
+            } else {
+                continue;
+            };
+
+            path.move_to((first.x, first.y));
+
+            for edge in edges {
+                let point = edge.end_point();
+                let point = (point.x, point.y);
+
+                match edge.segment_kind() {
+                    SegmentKind::Linear => path.line_to(point),
+                    SegmentKind::Quadratic => {
+                        let cpoint = edge.control_point(0).unwrap();
+                        path.quad_to((cpoint.x, cpoint.y), point);
+                    }
+                    SegmentKind::Cubic => {
+                        let c1point = edge.control_point(0).unwrap();
+                        let c2point = edge.control_point(1).unwrap();
+                        path.curve_to((c1point.x, c1point.y), (c2point.x, c2point.y), point);
+                    }
+                }
+            }
+
+            path.close_path();
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Point2;
+
+    #[test]
+    fn bez_path_conversion() {
+        let mut path = BezPath::new();
+        path.move_to((0.0, 0.0));
+        path.line_to((10.0, 0.0));
+        path.quad_to((15.0, 5.0), (10.0, 10.0));
+        path.close_path();
+        path.move_to((20.0, 0.0));
+        path.curve_to((20.0, 10.0), (30.0, 10.0), (30.0, 0.0));
+
+        let shape = Shape::from(&path);
+        assert_eq!(shape.contours().len(), 2);
+        assert!(shape.validate());
+
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges[0].segment_kind(), SegmentKind::Linear);
+        assert_eq!(edges[1].segment_kind(), SegmentKind::Quadratic);
+        assert_eq!(*edges[1].control_point(0).unwrap(), Point2::new(15.0, 5.0));
+        assert_eq!(edges[2].segment_kind(), SegmentKind::Linear);
+
+        // open subpath is closed
+        let edges = shape.contours()[1].edges();
+        assert_eq!(edges.len(), 2);
+        assert_eq!(edges[0].segment_kind(), SegmentKind::Cubic);
+        assert_eq!(*edges[1].end_point(), Point2::new(20.0, 0.0));
+
+        let path = shape.to_bez_path();
+        assert_eq!(path.elements().len(), 9);
+        assert_eq!(
+            path.elements()[6],
+            PathEl::CurveTo((20.0, 10.0).into(), (30.0, 10.0).into(), (30.0, 0.0).into())
+        );
+
+        let restored = Shape::from(&path);
+        assert_eq!(restored.to_bez_path(), path);
+    }
+}
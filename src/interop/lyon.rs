@@ -0,0 +1,142 @@
+use crate::{SegmentKind, Shape, ShapeBuilder};
+use lyon_path::{math::point, Path, PathEvent};
+
+impl Shape {
+    /// Creates shape from lyon path events
+    ///
+    /// Open subpaths are closed implicitly like when path is filled.
+    pub fn from_path_events(events: impl IntoIterator<Item = PathEvent>) -> Self {
+        let mut builder = ShapeBuilder::new();
+
+        for event in events {
+            match event {
+                PathEvent::Begin { at } => {
+                    builder.move_to((at.x as f64, at.y as f64));
+                }
+                PathEvent::Line { to, .. } => {
+                    builder.line_to((to.x as f64, to.y as f64));
+                }
+                PathEvent::Quadratic { ctrl, to, .. } => {
+                    builder.quad_to((ctrl.x as f64, ctrl.y as f64), (to.x as f64, to.y as f64));
+                }
+                PathEvent::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => {
+                    builder.curve_to(
+                        (ctrl1.x as f64, ctrl1.y as f64),
+                        (ctrl2.x as f64, ctrl2.y as f64),
+                        (to.x as f64, to.y as f64),
+                    );
+                }
+                PathEvent::End { .. } => {
+                    builder.close();
+                }
+            }
+        }
+
+        builder.build()
+    }
+
+    /// Converts shape into lyon path
+    ///
+    /// Each contour becomes closed subpath. Coordinates are converted to single precision.
+    pub fn to_lyon_path(&self) -> Path {
+        let mut builder = Path::builder();
+
+        for contour in self.contours() {
+            let edges = contour.edges();
+            let first = if let Some(first) = edges.first() {
+                first.start_point()
+            } else {
+                continue;
+            };
+
+            builder.begin(point(first.x as _, first.y as _));
+
+            for edge in edges {
+                let to = edge.end_point();
+                let to = point(to.x as _, to.y as _);
+
+                match edge.segment_kind() {
+                    SegmentKind::Linear => {
+                        builder.line_to(to);
+                    }
+                    SegmentKind::Quadratic => {
+                        let ctrl = edge.control_point(0).unwrap();
+                        builder.quadratic_bezier_to(point(ctrl.x as _, ctrl.y as _), to);
+                    }
+                    SegmentKind::Cubic => {
+                        let ctrl1 = edge.control_point(0).unwrap();
+                        let ctrl2 = edge.control_point(1).unwrap();
+                        builder.cubic_bezier_to(
+                            point(ctrl1.x as _, ctrl1.y as _),
+                            point(ctrl2.x as _, ctrl2.y as _),
+                            to,
+                        );
+                    }
+                }
+            }
+
+            builder.end(true);
+        }
+
+        builder.build()
+    }
+}
+
+impl From<&Path> for Shape {
+    fn from(path: &Path) -> Self {
+        Self::from_path_events(path.iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Point2;
+
+    #[test]
+    fn path_conversion() {
+        let mut builder = Path::builder();
+        builder.begin(point(0.0, 0.0));
+        builder.line_to(point(10.0, 0.0));
+        builder.quadratic_bezier_to(point(15.0, 5.0), point(10.0, 10.0));
+        builder.end(true);
+        builder.begin(point(20.0, 0.0));
+        builder.cubic_bezier_to(point(20.0, 10.0), point(30.0, 10.0), point(30.0, 0.0));
+        builder.end(false);
+        let path = builder.build();
+
+        let shape = Shape::from(&path);
+        assert_eq!(shape.contours().len(), 2);
+        assert!(shape.validate());
+
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges[1].segment_kind(), SegmentKind::Quadratic);
+        assert_eq!(*edges[2].end_point(), Point2::new(0.0, 0.0));
+
+        // open subpath is closed
+        let edges = shape.contours()[1].edges();
+        assert_eq!(edges.len(), 2);
+        assert_eq!(edges[0].segment_kind(), SegmentKind::Cubic);
+        assert_eq!(*edges[1].end_point(), Point2::new(20.0, 0.0));
+
+        let path = shape.to_lyon_path();
+        let events: Vec<_> = path.iter().collect();
+        assert_eq!(events.len(), 9);
+        assert_eq!(
+            events[2],
+            PathEvent::Quadratic {
+                from: point(10.0, 0.0),
+                ctrl: point(15.0, 5.0),
+                to: point(10.0, 10.0),
+            }
+        );
+
+        let restored = Shape::from(&path);
+        assert_eq!(restored.contours().len(), 2);
+        assert_eq!(restored.contours()[0].edges().len(), 3);
+        assert_eq!(restored.contours()[1].edges().len(), 2);
+    }
+}
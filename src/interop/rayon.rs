@@ -0,0 +1,77 @@
+use crate::{Bitmap, DistanceField, FieldKind, Generator, GeneratorOutput, GeneratorResult, Shape};
+use ::rayon::prelude::*;
+
+impl Generator {
+    /// Generates distance fields of shapes in parallel
+    ///
+    /// Like [`Generator::generate`] but for many shapes at once.
+    /// The results are in order of shapes.
+    pub fn generate_batch<T: GeneratorOutput + Send>(
+        &self,
+        shapes: &mut [Shape],
+    ) -> Vec<GeneratorResult<Bitmap<T>>> {
+        shapes
+            .par_iter_mut()
+            .map(|shape| self.generate(shape))
+            .collect()
+    }
+
+    /// Generates distance fields of specified kind for shapes in parallel
+    ///
+    /// Like [`Generator::generate_field`] but for many shapes at once.
+    /// The results are in order of shapes.
+    pub fn generate_field_batch(
+        &self,
+        kind: FieldKind,
+        shapes: &mut [Shape],
+    ) -> Vec<GeneratorResult<DistanceField>> {
+        shapes
+            .par_iter_mut()
+            .map(|shape| self.generate_field(kind, shape))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Rgb;
+
+    #[test]
+    fn batch() {
+        let paths = [
+            "M0 0 L0 10 L10 10 L10 0 Z",
+            "M1 1 L1 9 Q5 12 9 9 L9 1 Z",
+            "M0 0 L0 10 L10 10 Z",
+        ];
+        let shapes = || {
+            paths
+                .iter()
+                .map(|path| Shape::from_svg_path_data(path).unwrap())
+                .collect::<Vec<_>>()
+        };
+        let generator = Generator::default().with_size(16, 16);
+
+        let mut batch_shapes = shapes();
+        let results = generator.generate_batch::<Rgb<f32>>(&mut batch_shapes);
+        let field_results = generator.generate_field_batch(FieldKind::Msdf, &mut shapes());
+
+        assert_eq!(results.len(), paths.len());
+        for (((result, field_result), mut shape), batch_shape) in results
+            .into_iter()
+            .zip(field_results)
+            .zip(shapes())
+            .zip(&batch_shapes)
+        {
+            let (bitmap, framing) = result.unwrap();
+            let (field, field_framing) = field_result.unwrap();
+            let (expected, expected_framing) = generator.generate::<Rgb<f32>>(&mut shape).unwrap();
+
+            assert_eq!(framing, expected_framing);
+            assert_eq!(field_framing, expected_framing);
+            assert_eq!(bitmap.pixels(), expected.pixels());
+            assert_eq!(field.raw_pixels(), expected.raw_pixels());
+            assert_eq!(batch_shape.to_description(), shape.to_description());
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{
+    Contour, DistanceCheckMode, EdgeColor, EdgeHolder, ErrorCorrectionConfig, ErrorCorrectionMode,
+    GeneratorConfig, MsdfGeneratorConfig, Point2, SegmentKind, Shape,
+};
+use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialized form of edge
+///
+/// The kind of segment is determined by the number of points.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "EdgeHolder")]
+struct EdgeData {
+    color: EdgeColor,
+    points: Vec<Point2<f64>>,
+}
+
+impl Serialize for EdgeHolder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut points = vec![*self.start_point()];
+        let controls = match self.segment_kind() {
+            SegmentKind::Linear => 0,
+            SegmentKind::Quadratic => 1,
+            SegmentKind::Cubic => 2,
+        };
+        points.extend((0..controls).map(|num| *self.control_point(num).unwrap()));
+        points.push(*self.end_point());
+
+        EdgeData {
+            color: *self.edge_color(),
+            points,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EdgeHolder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let EdgeData { color, points } = EdgeData::deserialize(deserializer)?;
+        Ok(match points[..] {
+            [p0, p1] => EdgeHolder::new_linear(p0, p1, color),
+            [p0, p1, p2] => EdgeHolder::new_quadratic(p0, p1, p2, color),
+            [p0, p1, p2, p3] => EdgeHolder::new_cubic(p0, p1, p2, p3, color),
+            _ => return Err(D::Error::invalid_length(points.len(), &"2 to 4 points")),
+        })
+    }
+}
+
+impl Serialize for Contour {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.edges())
+    }
+}
+
+impl<'de> Deserialize<'de> for Contour {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let edges = Vec::<EdgeHolder>::deserialize(deserializer)?;
+        let mut contour = Contour::default();
+        for edge in &edges {
+            contour.add_edge(edge);
+        }
+        Ok(contour)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Shape")]
+struct ShapeRef<'a> {
+    contours: &'a [Contour],
+    inverse_y_axis: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Shape")]
+struct ShapeData {
+    contours: Vec<Contour>,
+    inverse_y_axis: bool,
+}
+
+impl Serialize for Shape {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ShapeRef {
+            contours: self.contours(),
+            inverse_y_axis: self.get_inverse_y_axis(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Shape {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = ShapeData::deserialize(deserializer)?;
+        let mut shape = Shape::default();
+        for contour in &data.contours {
+            shape.add_contour(contour);
+        }
+        shape.set_inverse_y_axis(data.inverse_y_axis);
+        Ok(shape)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "GeneratorConfig")]
+struct GeneratorConfigData {
+    overlap_support: bool,
+}
+
+impl Serialize for GeneratorConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GeneratorConfigData {
+            overlap_support: self.get_overlap_support(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GeneratorConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = GeneratorConfigData::deserialize(deserializer)?;
+        Ok(GeneratorConfig::default().with_overlap_support(data.overlap_support))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ErrorCorrectionConfig")]
+struct ErrorCorrectionConfigData {
+    mode: ErrorCorrectionMode,
+    distance_check_mode: DistanceCheckMode,
+    min_deviation_ratio: f64,
+    min_improve_ratio: f64,
+}
+
+impl Serialize for ErrorCorrectionConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ErrorCorrectionConfigData {
+            mode: self.get_mode(),
+            distance_check_mode: self.get_distance_check_mode(),
+            min_deviation_ratio: self.get_min_deviation_ratio(),
+            min_improve_ratio: self.get_min_improve_ratio(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCorrectionConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = ErrorCorrectionConfigData::deserialize(deserializer)?;
+        Ok(ErrorCorrectionConfig::default()
+            .with_mode(data.mode)
+            .with_distance_check_mode(data.distance_check_mode)
+            .with_min_deviation_ratio(data.min_deviation_ratio)
+            .with_min_improve_ratio(data.min_improve_ratio))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "MsdfGeneratorConfig")]
+struct MsdfGeneratorConfigData {
+    overlap_support: bool,
+    error_correction: ErrorCorrectionConfig,
+}
+
+impl Serialize for MsdfGeneratorConfig {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MsdfGeneratorConfigData {
+            overlap_support: self.get_overlap_support(),
+            error_correction: *(self.as_ref() as &ErrorCorrectionConfig),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MsdfGeneratorConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = MsdfGeneratorConfigData::deserialize(deserializer)?;
+        let mut config = MsdfGeneratorConfig::default().with_overlap_support(data.overlap_support);
+        *(config.as_mut() as &mut ErrorCorrectionConfig) = data.error_correction;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Bound, FillRule, Framing, Projection, Range, Vector2};
+
+    fn json<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+        serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+    }
+
+    fn bincode<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+        bincode::deserialize(&bincode::serialize(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn shape_round_trip() {
+        let mut shape =
+            Shape::from_svg_path_data("M0 0 L0 10 Q5 15 10 10 C12 8 12 2 10 0 Z M3 3 L7 3 L7 7 Z")
+                .unwrap();
+        shape.edge_coloring_simple(3.0, 0);
+        shape.set_inverse_y_axis(true);
+
+        let description = shape.to_description();
+        assert_eq!(json(&shape).to_description(), description);
+        assert_eq!(bincode(&shape).to_description(), description);
+
+        let edge = &shape.contours()[0].edges()[1];
+        assert_eq!(
+            serde_json::to_value(edge).unwrap(),
+            serde_json::json!({
+                "color": format!("{:?}", edge.edge_color()),
+                "points": [{"x": 0.0, "y": 10.0}, {"x": 5.0, "y": 15.0}, {"x": 10.0, "y": 10.0}],
+            })
+        );
+
+        assert!(serde_json::from_str::<EdgeHolder>(
+            r#"{"color":"White","points":[{"x":0.0,"y":0.0}]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn configs_round_trip() {
+        let config = MsdfGeneratorConfig::default()
+            .with_overlap_support(false)
+            .with_mode(ErrorCorrectionMode::EdgeOnly)
+            .with_distance_check_mode(DistanceCheckMode::AlwaysCheck)
+            .with_min_deviation_ratio(1.5)
+            .with_min_improve_ratio(2.5);
+
+        for config in [json(&config), bincode(&config)] {
+            assert!(!config.get_overlap_support());
+            assert!(matches!(config.get_mode(), ErrorCorrectionMode::EdgeOnly));
+            assert!(matches!(
+                config.get_distance_check_mode(),
+                DistanceCheckMode::AlwaysCheck
+            ));
+            assert_eq!(config.get_min_deviation_ratio(), 1.5);
+            assert_eq!(config.get_min_improve_ratio(), 2.5);
+        }
+
+        let config = GeneratorConfig::default().with_overlap_support(false);
+        assert!(!json(&config).get_overlap_support());
+        assert!(!bincode(&config).get_overlap_support());
+
+        let config = ErrorCorrectionConfig::default().with_min_improve_ratio(3.0);
+        assert_eq!(json(&config).get_min_improve_ratio(), 3.0);
+        assert_eq!(bincode(&config).get_min_improve_ratio(), 3.0);
+    }
+
+    #[test]
+    fn framing_round_trip() {
+        let framing = Framing {
+            projection: Projection::new((2.0, 3.0), (-1.0, 0.5)),
+            range: 4.0,
+        };
+        assert_eq!(json(&framing), framing);
+        assert_eq!(bincode(&framing), framing);
+
+        let bound = Bound::new(0.0, 1.0, 2.0, 3.0);
+        assert_eq!(json(&bound), bound);
+        assert_eq!(bincode(&bound), bound);
+
+        assert_eq!(json(&Range::Px(2.0)), Range::Px(2.0));
+        assert_eq!(bincode(&Range::Unit(0.5)), Range::Unit(0.5));
+        assert_eq!(json(&Vector2::new(1, 2)), Vector2::new(1, 2));
+        assert_eq!(json(&FillRule::Odd), FillRule::Odd);
+        assert_eq!(bincode(&EdgeColor::Cyan), EdgeColor::Cyan);
+    }
+}
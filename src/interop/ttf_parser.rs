@@ -1,110 +1,34 @@
-use crate::{Contour, EdgeColor, EdgeHolder, FontExt, Point2, Shape};
-
-#[derive(Default)]
-struct ContourBuilder {
-    contour: Contour,
-    point: Point2<f64>,
-    start_point: Point2<f64>,
-}
-
-impl ContourBuilder {
-    pub fn open_at(x: f64, y: f64) -> Self {
-        Self {
-            contour: Contour::default(),
-            point: Point2::new(x, y),
-            start_point: Point2::new(x, y),
-        }
-    }
-
-    pub fn line_to(&mut self, x: f64, y: f64) {
-        let point = Point2::new(x, y);
-        self.contour.add_edge(&EdgeHolder::new_linear(
-            self.point,
-            point,
-            EdgeColor::default(),
-        ));
-        self.point = point;
-    }
-
-    pub fn quad_to(&mut self, cx: f64, cy: f64, x: f64, y: f64) {
-        let cpoint = Point2::new(cx, cy);
-        let point = Point2::new(x, y);
-        self.contour.add_edge(&EdgeHolder::new_quadratic(
-            self.point,
-            cpoint,
-            point,
-            EdgeColor::default(),
-        ));
-        self.point = point;
-    }
-
-    pub fn curve_to(&mut self, c1x: f64, c1y: f64, c2x: f64, c2y: f64, x: f64, y: f64) {
-        let c1point = Point2::new(c1x, c1y);
-        let c2point = Point2::new(c2x, c2y);
-        let point = Point2::new(x, y);
-        self.contour.add_edge(&EdgeHolder::new_cubic(
-            self.point,
-            c1point,
-            c2point,
-            point,
-            EdgeColor::default(),
-        ));
-        self.point = point;
-    }
-
-    pub fn close(self) -> Contour {
-        self.contour
-    }
-}
-
-#[derive(Default)]
-struct ShapeBuilder {
-    shape: Shape,
-    contour: Option<ContourBuilder>,
-}
-
-impl ShapeBuilder {
-    pub fn build(self) -> Shape {
-        self.shape
-    }
-}
+use crate::{ColorFontExt, FontExt, FontMetrics, FontMetricsExt, Rgba, Shape, ShapeBuilder};
+use std::collections::{BTreeMap, HashMap};
+use ttf_parser::{
+    kern::{Format, Table},
+    GlyphId,
+};
 
 impl ttf_parser::OutlineBuilder for ShapeBuilder {
     fn move_to(&mut self, x: f32, y: f32) {
-        if self.contour.is_some() {
-            panic!("Unexpected move_to");
-        }
-
-        self.contour = ContourBuilder::open_at(x as _, y as _).into();
+        ShapeBuilder::move_to(self, (x as f64, y as f64));
     }
 
     fn line_to(&mut self, x: f32, y: f32) {
-        self.contour
-            .as_mut()
-            .expect("Opened contour")
-            .line_to(x as _, y as _);
+        ShapeBuilder::line_to(self, (x as f64, y as f64));
     }
 
     fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
-        self.contour
-            .as_mut()
-            .expect("Opened contour")
-            .quad_to(x1 as _, y1 as _, x as _, y as _);
+        ShapeBuilder::quad_to(self, (x1 as f64, y1 as f64), (x as f64, y as f64));
     }
 
     fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
-        self.contour
-            .as_mut()
-            .expect("Opened contour")
-            .curve_to(x1 as _, y1 as _, x2 as _, y2 as _, x as _, y as _);
+        ShapeBuilder::curve_to(
+            self,
+            (x1 as f64, y1 as f64),
+            (x2 as f64, y2 as f64),
+            (x as f64, y as f64),
+        );
     }
 
     fn close(&mut self) {
-        let mut contour = self.contour.take().expect("Opened contour");
-        if contour.point != contour.start_point {
-            contour.line_to(contour.start_point.x as _, contour.start_point.y as _);
-        }
-        self.shape.add_contour(&contour.close());
+        ShapeBuilder::close(self);
     }
 }
 
@@ -120,11 +44,284 @@ impl<'a> FontExt for ttf_parser::Face<'a> {
     }
 }
 
+impl<'a> FontMetricsExt for ttf_parser::Face<'a> {
+    fn units_per_em(&self) -> f64 {
+        ttf_parser::Face::units_per_em(self) as _
+    }
+
+    fn glyph_index(&self, chr: char) -> Option<Self::Glyph> {
+        ttf_parser::Face::glyph_index(self, chr)
+    }
+
+    fn glyph_advance(&self, glyph: Self::Glyph) -> Option<f64> {
+        self.glyph_hor_advance(glyph).map(|advance| advance as _)
+    }
+
+    fn font_metrics(&self) -> FontMetrics {
+        let underline = self.underline_metrics();
+
+        FontMetrics {
+            ascender: self.ascender() as _,
+            descender: self.descender() as _,
+            line_height: self.height() as f64 + self.line_gap() as f64,
+            underline_y: underline.map(|line| line.position as _).unwrap_or_default(),
+            underline_thickness: underline
+                .map(|line| line.thickness as _)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn glyph_kerning(&self, left: Self::Glyph, right: Self::Glyph) -> Option<f64> {
+        self.tables()
+            .kern?
+            .subtables
+            .into_iter()
+            .filter(|subtable| subtable.horizontal && !subtable.variable)
+            .find_map(|subtable| subtable.glyphs_kerning(left, right))
+            .map(|kerning| kerning as _)
+    }
+
+    fn glyphs_kerning(&self, glyphs: &[Self::Glyph]) -> Vec<(usize, usize, f64)> {
+        self.tables()
+            .kern
+            .map(|kern| kern_pairs(kern, glyphs))
+            .unwrap_or_default()
+    }
+}
+
+impl<'a> ColorFontExt for ttf_parser::Face<'a> {
+    fn glyph_color_layers(
+        &self,
+        glyph: Self::Glyph,
+        palette: u16,
+        foreground: Rgba<u8>,
+    ) -> Option<Vec<(Shape, Rgba<u8>)>> {
+        let colr = self
+            .raw_face()
+            .table(ttf_parser::Tag::from_bytes(b"COLR"))?;
+        let cpal = self.raw_face().table(ttf_parser::Tag::from_bytes(b"CPAL"));
+
+        let layers = colr_layers(colr, glyph.0)?
+            .filter_map(|(layer_glyph, palette_index)| {
+                let color = cpal
+                    .and_then(|cpal| cpal_color(cpal, palette, palette_index))
+                    .unwrap_or(foreground);
+                let shape = self.glyph_shape(ttf_parser::GlyphId(layer_glyph))?;
+                Some((shape, color))
+            })
+            .collect();
+
+        Some(layers)
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Finds COLRv0 layers of glyph as pairs of layer glyph and palette entry index
+fn colr_layers(colr: &[u8], glyph: u16) -> Option<impl Iterator<Item = (u16, u16)> + '_> {
+    const BASE_GLYPH_RECORD_SIZE: usize = 6;
+    const LAYER_RECORD_SIZE: usize = 4;
+
+    let num_base_glyphs = read_u16(colr, 2)? as usize;
+    let base_glyphs_offset = read_u32(colr, 4)? as usize;
+    let layers_offset = read_u32(colr, 8)? as usize;
+    let num_layers = read_u16(colr, 12)? as usize;
+
+    let base_glyphs = colr
+        .get(base_glyphs_offset..base_glyphs_offset + num_base_glyphs * BASE_GLYPH_RECORD_SIZE)?;
+
+    // base glyph records are sorted by glyph id
+    let (mut lo, mut hi) = (0, num_base_glyphs);
+    let (first_layer, layers_count) = loop {
+        if lo >= hi {
+            return None;
+        }
+        let mid = (lo + hi) / 2;
+        let record = mid * BASE_GLYPH_RECORD_SIZE;
+        let base_glyph = read_u16(base_glyphs, record)?;
+        match base_glyph.cmp(&glyph) {
+            core::cmp::Ordering::Less => lo = mid + 1,
+            core::cmp::Ordering::Greater => hi = mid,
+            core::cmp::Ordering::Equal => {
+                break (
+                    read_u16(base_glyphs, record + 2)? as usize,
+                    read_u16(base_glyphs, record + 4)? as usize,
+                )
+            }
+        }
+    };
+
+    if first_layer + layers_count > num_layers {
+        return None;
+    }
+
+    let layers = colr.get(
+        layers_offset + first_layer * LAYER_RECORD_SIZE
+            ..layers_offset + (first_layer + layers_count) * LAYER_RECORD_SIZE,
+    )?;
+
+    Some(layers.chunks_exact(LAYER_RECORD_SIZE).map(|record| {
+        (
+            u16::from_be_bytes([record[0], record[1]]),
+            u16::from_be_bytes([record[2], record[3]]),
+        )
+    }))
+}
+
+/// Gets color of palette entry
+///
+/// Returns `None` for foreground color entry (0xffff) and invalid entries.
+fn cpal_color(cpal: &[u8], palette: u16, palette_index: u16) -> Option<Rgba<u8>> {
+    const COLOR_RECORD_SIZE: usize = 4;
+
+    let num_entries = read_u16(cpal, 2)?;
+    let num_palettes = read_u16(cpal, 4)?;
+    let colors_offset = read_u32(cpal, 8)? as usize;
+
+    if palette >= num_palettes || palette_index >= num_entries {
+        return None;
+    }
+
+    let first_color = read_u16(cpal, 12 + palette as usize * 2)? as usize;
+    let record = colors_offset + (first_color + palette_index as usize) * COLOR_RECORD_SIZE;
+    let color = cpal.get(record..record + COLOR_RECORD_SIZE)?;
+
+    // color records are stored in BGRA order
+    Some(Rgba::new(color[2], color[1], color[0], color[3]))
+}
+
+/// Gets the non-zero kerning between glyphs of set from kern table
+///
+/// Format 0 subtables are enumerated, so only the pairs which are present in table are looked up.
+fn kern_pairs(kern: Table, glyphs: &[GlyphId]) -> Vec<(usize, usize, f64)> {
+    let mut indices = HashMap::<GlyphId, Vec<usize>>::new();
+    for (index, glyph) in glyphs.iter().enumerate() {
+        indices.entry(*glyph).or_default().push(index);
+    }
+
+    // the first subtable which has the pair takes precedence like in glyph_kerning
+    let mut pairs = BTreeMap::new();
+
+    for subtable in kern
+        .subtables
+        .into_iter()
+        .filter(|subtable| subtable.horizontal && !subtable.variable)
+    {
+        if let Format::Format0(table) = &subtable.format {
+            for pair in table.pairs {
+                if let (Some(lefts), Some(rights)) =
+                    (indices.get(&pair.left()), indices.get(&pair.right()))
+                {
+                    for left in lefts {
+                        for right in rights {
+                            pairs.entry((*left, *right)).or_insert(pair.value);
+                        }
+                    }
+                }
+            }
+        } else {
+            // class based subtables cannot be enumerated
+            for (left, left_glyph) in glyphs.iter().enumerate() {
+                for (right, right_glyph) in glyphs.iter().enumerate() {
+                    if let Some(value) = subtable.glyphs_kerning(*left_glyph, *right_glyph) {
+                        pairs.entry((left, right)).or_insert(value);
+                    }
+                }
+            }
+        }
+    }
+
+    pairs
+        .into_iter()
+        .filter(|(_, value)| *value != 0)
+        .map(|((left, right), value)| (left, right, value as _))
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use notosans::REGULAR_TTF;
-    use ttf_parser::{Face, GlyphId};
+    use ttf_parser::Face;
+
+    #[rustfmt::skip]
+    const COLR: &[u8] = &[
+        0, 0, // version
+        0, 2, // number of base glyphs
+        0, 0, 0, 14, // base glyphs offset
+        0, 0, 0, 26, // layers offset
+        0, 3, // number of layers
+        // base glyphs
+        0, 5, 0, 0, 0, 2,
+        0, 9, 0, 2, 0, 1,
+        // layers
+        0, 10, 0, 1,
+        0, 11, 0xff, 0xff,
+        0, 12, 0, 0,
+    ];
+
+    #[rustfmt::skip]
+    const CPAL: &[u8] = &[
+        0, 0, // version
+        0, 2, // number of palette entries
+        0, 2, // number of palettes
+        0, 4, // number of color records
+        0, 0, 0, 16, // color records offset
+        0, 0, 0, 2, // first color records of palettes
+        // color records (BGRA)
+        0, 0, 255, 255,
+        0, 255, 0, 255,
+        255, 0, 0, 255,
+        0, 0, 0, 128,
+    ];
+
+    #[rustfmt::skip]
+    const KERN: &[u8] = &[
+        0, 0, // version
+        0, 2, // number of subtables
+        // first subtable
+        0, 0, // version
+        0, 26, // length
+        0, 1, // format 0, horizontal
+        0, 2, 0, 12, 0, 1, 0, 0, // number of pairs and search parameters
+        0, 1, 0, 2, 0xff, 0xce, // 1, 2: -50
+        0, 2, 0, 1, 0, 0, // 2, 1: 0
+        // second subtable
+        0, 0, // version
+        0, 32, // length
+        0, 1, // format 0, horizontal
+        0, 3, 0, 12, 0, 1, 0, 6, // number of pairs and search parameters
+        0, 1, 0, 2, 0xff, 0xf6, // 1, 2: -10 (overridden by first subtable)
+        0, 2, 0, 1, 0, 20, // 2, 1: 20 (overridden by first subtable)
+        0, 2, 0, 3, 0, 30, // 2, 3: 30
+    ];
+
+    #[test]
+    fn colr_layers_lookup() {
+        assert_eq!(
+            colr_layers(COLR, 5).unwrap().collect::<Vec<_>>(),
+            [(10, 1), (11, 0xffff)]
+        );
+        assert_eq!(colr_layers(COLR, 9).unwrap().collect::<Vec<_>>(), [(12, 0)]);
+        assert!(colr_layers(COLR, 7).is_none());
+    }
+
+    #[test]
+    fn cpal_color_lookup() {
+        assert_eq!(cpal_color(CPAL, 0, 0), Some(Rgba::new(255, 0, 0, 255)));
+        assert_eq!(cpal_color(CPAL, 0, 1), Some(Rgba::new(0, 255, 0, 255)));
+        assert_eq!(cpal_color(CPAL, 1, 1), Some(Rgba::new(0, 0, 0, 128)));
+        assert_eq!(cpal_color(CPAL, 0, 0xffff), None);
+        assert_eq!(cpal_color(CPAL, 2, 0), None);
+    }
 
     #[test]
     fn glyph_shape() {
@@ -141,4 +338,31 @@ mod test {
 
         assert_eq!(shapes, 2392);
     }
+
+    #[test]
+    fn kern_pairs_lookup() {
+        let kern = Table::parse(KERN).unwrap();
+        let glyphs = [GlyphId(1), GlyphId(2), GlyphId(3), GlyphId(2)];
+
+        assert_eq!(
+            kern_pairs(kern, &glyphs),
+            [(0, 1, -50.0), (0, 3, -50.0), (1, 2, 30.0), (3, 2, 30.0)]
+        );
+
+        // same as looking up each pair
+        let mut expected = Vec::new();
+        for (left, left_glyph) in glyphs.iter().enumerate() {
+            for (right, right_glyph) in glyphs.iter().enumerate() {
+                let kerning = kern
+                    .subtables
+                    .into_iter()
+                    .find_map(|subtable| subtable.glyphs_kerning(*left_glyph, *right_glyph));
+                match kerning {
+                    Some(kerning) if kerning != 0 => expected.push((left, right, kerning as f64)),
+                    _ => {}
+                }
+            }
+        }
+        assert_eq!(kern_pairs(kern, &glyphs), expected);
+    }
 }
@@ -0,0 +1,188 @@
+use crate::{FillRule, Shape, ShapeBuilder};
+use usvg::{tiny_skia_path::PathSegment, Group, Node, Path, Tree};
+
+/// Shape of single element of SVG document
+pub struct SvgElementShape {
+    /// Identifier of element (may be empty)
+    pub id: String,
+    /// Outline of element with transforms applied
+    pub shape: Shape,
+    /// Fill rule of element
+    pub fill_rule: FillRule,
+}
+
+/// Extensions for SVG document trees
+///
+/// Only visible elements which have fill are converted. Strokes, images, clip paths and masks are ignored.
+/// Text elements are converted using their flattened outlines.
+///
+/// The coordinates are taken as is, so y-axis points down like in SVG
+/// and the shapes have inverse Y axis (see [`Shape::set_inverse_y_axis`]).
+pub trait SvgTreeExt {
+    /// Flattens all elements of document into single shape
+    ///
+    /// Returns the shape with fill rule of document (the rule of first element),
+    /// which can be passed to [`Shape::correct_sign`].
+    fn svg_shape(&self) -> (Shape, FillRule);
+
+    /// Converts each element of document into separate shape
+    fn svg_element_shapes(&self) -> Vec<SvgElementShape>;
+}
+
+impl SvgTreeExt for Tree {
+    fn svg_shape(&self) -> (Shape, FillRule) {
+        let mut builder = ShapeBuilder::new();
+        let mut fill_rule = None;
+
+        visit_paths(self.root(), &mut |path| {
+            fill_rule.get_or_insert_with(|| path_fill_rule(path));
+            add_path(&mut builder, path);
+        });
+
+        let mut shape = builder.build();
+        shape.set_inverse_y_axis(true);
+
+        (shape, fill_rule.unwrap_or_default())
+    }
+
+    fn svg_element_shapes(&self) -> Vec<SvgElementShape> {
+        let mut elements = Vec::new();
+
+        visit_paths(self.root(), &mut |path| {
+            let mut builder = ShapeBuilder::new();
+            add_path(&mut builder, path);
+            let mut shape = builder.build();
+            shape.set_inverse_y_axis(true);
+            elements.push(SvgElementShape {
+                id: path.id().into(),
+                shape,
+                fill_rule: path_fill_rule(path),
+            });
+        });
+
+        elements
+    }
+}
+
+impl Shape {
+    /// Creates shape from SVG document
+    ///
+    /// See [`SvgTreeExt::svg_shape`].
+    pub fn from_svg_document(data: &str) -> Result<(Shape, FillRule), usvg::Error> {
+        let tree = Tree::from_str(data, &usvg::Options::default())?;
+        Ok(tree.svg_shape())
+    }
+}
+
+fn visit_paths(group: &Group, visit: &mut impl FnMut(&Path)) {
+    for node in group.children() {
+        match node {
+            Node::Group(group) => visit_paths(group, visit),
+            Node::Path(path) => {
+                if path.is_visible() && path.fill().is_some() {
+                    visit(path);
+                }
+            }
+            Node::Text(text) => visit_paths(text.flattened(), visit),
+            Node::Image(_) => {}
+        }
+    }
+}
+
+fn path_fill_rule(path: &Path) -> FillRule {
+    match path.fill().map(|fill| fill.rule()) {
+        Some(usvg::FillRule::EvenOdd) => FillRule::Odd,
+        _ => FillRule::NonZero,
+    }
+}
+
+fn add_path(builder: &mut ShapeBuilder, path: &Path) {
+    let transform = path.abs_transform();
+    let map = |mut point: usvg::tiny_skia_path::Point| {
+        transform.map_point(&mut point);
+        (point.x as f64, point.y as f64)
+    };
+
+    for segment in path.data().segments() {
+        match segment {
+            PathSegment::MoveTo(point) => {
+                builder.move_to(map(point));
+            }
+            PathSegment::LineTo(point) => {
+                builder.line_to(map(point));
+            }
+            PathSegment::QuadTo(cpoint, point) => {
+                builder.quad_to(map(cpoint), map(point));
+            }
+            PathSegment::CubicTo(c1point, c2point, point) => {
+                builder.curve_to(map(c1point), map(c2point), map(point));
+            }
+            PathSegment::Close => {
+                builder.close();
+            }
+        }
+    }
+
+    // filled subpaths are closed implicitly
+    builder.close();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn document_shape() {
+        let (shape, fill_rule) = Shape::from_svg_document(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+                <g transform="translate(10 20)">
+                    <rect x="0" y="0" width="30" height="10" transform="scale(2)"/>
+                </g>
+                <path d="M 0 0 L 10 0 L 10 10" fill="none" stroke="black"/>
+                <circle cx="80" cy="80" r="10" fill-rule="evenodd"/>
+            </svg>"#,
+        )
+        .unwrap();
+
+        assert_eq!(fill_rule, FillRule::NonZero);
+        assert_eq!(shape.contours().len(), 2);
+        assert!(shape.validate());
+        assert!(shape.get_inverse_y_axis());
+
+        let bound = shape.get_tight_bound();
+        assert!((bound.left - 10.0).abs() < 1e-4);
+        assert!((bound.bottom - 20.0).abs() < 1e-4);
+        assert!((bound.right - 90.0).abs() < 1e-4);
+        assert!((bound.top - 90.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn element_shapes() {
+        let tree = Tree::from_str(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+                <path id="frame" d="M 10 10 H 90 V 90 H 10 Z M 20 20 H 80 V 80 H 20 Z" fill-rule="evenodd"/>
+                <rect id="dot" x="40" y="40" width="20" height="20" transform="rotate(45 50 50)"/>
+            </svg>"#,
+            &usvg::Options::default(),
+        )
+        .unwrap();
+
+        let (shape, fill_rule) = tree.svg_shape();
+        assert_eq!(fill_rule, FillRule::Odd);
+        assert_eq!(shape.contours().len(), 3);
+
+        let elements = tree.svg_element_shapes();
+        assert_eq!(elements.len(), 2);
+
+        assert_eq!(elements[0].id, "frame");
+        assert_eq!(elements[0].fill_rule, FillRule::Odd);
+        assert_eq!(elements[0].shape.contours().len(), 2);
+
+        assert_eq!(elements[1].id, "dot");
+        assert_eq!(elements[1].fill_rule, FillRule::NonZero);
+        let bound = elements[1].shape.get_tight_bound();
+        let half_diagonal = 10.0 * 2f64.sqrt();
+        assert!((bound.left - (50.0 - half_diagonal)).abs() < 1e-4);
+        assert!((bound.top - (50.0 + half_diagonal)).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Bitmap, Bound, Framing, MsdfGeneratorConfig, Rgb, Rgba, Shape};
+
+/// Shape layer with color
+pub type ColorLayer = (Shape, Rgba<u8>);
+
+/// Gets the bounding box to fit shapes of all layers
+pub fn layers_bound(layers: &[ColorLayer]) -> Bound<f64> {
+    let mut bound = Bound::default();
+    for (shape, _) in layers {
+        shape.bound(&mut bound);
+    }
+    bound
+}
+
+/// Generates multi-channel signed distance field for each layer using shared framing
+///
+/// The framing usually is created by autoframing of [`layers_bound`] so all fields are aligned to each other.
+/// Shapes should be edge colored before (see [`Shape::edge_coloring_simple`]).
+pub fn generate_layers_msdf(
+    layers: &[ColorLayer],
+    width: u32,
+    height: u32,
+    framing: impl AsRef<Framing<f64>>,
+    config: impl AsRef<MsdfGeneratorConfig>,
+) -> Vec<Bitmap<Rgb<f32>>> {
+    let framing = framing.as_ref();
+    let config = config.as_ref();
+
+    layers
+        .iter()
+        .map(|(shape, _)| {
+            let mut bitmap = Bitmap::new(width, height);
+            shape.generate_msdf(&mut bitmap, framing, config);
+            bitmap
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{shape::test::square, Range};
+
+    #[test]
+    fn shared_framing() {
+        let mut layers = vec![
+            (square(0.0, 0.0, 1.0), Rgba::new(255, 0, 0, 255)),
+            (square(1.0, 1.0, 1.0), Rgba::new(0, 0, 255, 255)),
+        ];
+
+        for (shape, _) in &mut layers {
+            shape.edge_coloring_simple(3.0, 0);
+        }
+
+        let bound = layers_bound(&layers);
+        assert_eq!(bound, Bound::new(0.0, 0.0, 2.0, 2.0));
+
+        let framing = bound.autoframe(16, 16, Range::Px(2.0), None).unwrap();
+        let bitmaps =
+            generate_layers_msdf(&layers, 16, 16, framing, MsdfGeneratorConfig::default());
+
+        assert_eq!(bitmaps.len(), 2);
+        // bottom-left pixel is inside the first layer only
+        assert!(bitmaps[0].pixel(2, 2).r > 0.5);
+        assert!(bitmaps[1].pixel(2, 2).r < 0.5);
+    }
+}
@@ -1,35 +1,68 @@
 #![doc = include_str!("../README.md")]
 
+mod affine;
+mod atlas;
 mod bitmap;
+mod boolean;
 mod bound;
+mod builder;
+mod charset;
 mod config;
 mod contour;
 mod correct;
+mod defect;
+mod description;
+mod dynamic;
 mod edge;
+mod field;
 mod generate;
+mod generator;
+mod geometry;
 mod interop;
+mod layer;
+mod metadata;
+mod metrics;
 mod polarity;
 mod render;
 mod scanline;
 mod segment;
 mod shape;
+mod simplify;
+mod stroke;
+mod svg;
+mod text;
 mod vector;
 
 pub(crate) use msdfgen_sys as ffi;
 
+pub use affine::*;
+pub use atlas::*;
 pub use bitmap::*;
+pub use boolean::*;
 pub use bound::*;
+pub use builder::*;
+pub use charset::*;
 pub use config::*;
 pub use contour::*;
 pub use correct::*;
+pub use defect::*;
+pub use description::*;
+pub use dynamic::*;
 pub use edge::*;
+pub use field::*;
 pub use generate::*;
+pub use generator::*;
 pub use interop::*;
+pub use layer::*;
+pub use metrics::*;
 pub use polarity::*;
 pub use render::*;
 pub use scanline::*;
 pub use segment::*;
 pub use shape::*;
+pub use stroke::*;
+pub use svg::*;
+pub use text::*;
 pub use vector::*;
 
 // Run via: cargo test --features "png,ttf-parser"
@@ -0,0 +1,209 @@
+use crate::{Atlas, Bound, FieldGeneration};
+use core::fmt::{Display, Formatter, Result, Write};
+
+impl<T: FieldGeneration> Atlas<T> {
+    /// Writes atlas metadata using JSON layout of msdf-atlas-gen
+    ///
+    /// The output contains `atlas`, `metrics`, `glyphs` and `kerning` sections.
+    /// Font metrics and plane bounds are given in ems and atlas bounds are given in pixels
+    /// with origin at the bottom of page.
+    ///
+    /// The `page` index of glyph is written only for atlases which have more than one page.
+    /// Non-finite numbers are written as `null` because JSON cannot represent them.
+    pub fn write_json(&self, out: &mut impl Write) -> Result {
+        let (width, height) = self
+            .pages
+            .first()
+            .map(|page| (page.width(), page.height()))
+            .unwrap_or_default();
+
+        write!(
+            out,
+            r#"{{"atlas":{{"type":"{}","distanceRange":{},"size":{},"width":{},"height":{},"yOrigin":"bottom"}}"#,
+            T::FIELD_TYPE,
+            Number(self.range),
+            Number(self.em_size),
+            width,
+            height,
+        )?;
+
+        write!(
+            out,
+            r#","metrics":{{"emSize":1,"lineHeight":{},"ascender":{},"descender":{},"underlineY":{},"underlineThickness":{}}}"#,
+            Number(self.metrics.line_height),
+            Number(self.metrics.ascender),
+            Number(self.metrics.descender),
+            Number(self.metrics.underline_y),
+            Number(self.metrics.underline_thickness),
+        )?;
+
+        out.write_str(r#","glyphs":["#)?;
+
+        for (index, glyph) in self.glyphs.iter().enumerate() {
+            if index > 0 {
+                out.write_char(',')?;
+            }
+
+            write!(
+                out,
+                r#"{{"unicode":{},"advance":{}"#,
+                glyph.chr as u32,
+                Number(glyph.advance)
+            )?;
+
+            if let Some(placement) = &glyph.placement {
+                if self.pages.len() > 1 {
+                    write!(out, r#","page":{}"#, placement.page)?;
+                }
+                out.write_str(r#","planeBounds":"#)?;
+                write_bound(out, &placement.plane_bound)?;
+                out.write_str(r#","atlasBounds":"#)?;
+                write_bound(out, &placement.atlas_bound)?;
+            }
+
+            out.write_char('}')?;
+        }
+
+        out.write_str(r#"],"kerning":["#)?;
+
+        for (index, kerning) in self.kerning.iter().enumerate() {
+            if index > 0 {
+                out.write_char(',')?;
+            }
+
+            write!(
+                out,
+                r#"{{"unicode1":{},"unicode2":{},"advance":{}}}"#,
+                kerning.left as u32,
+                kerning.right as u32,
+                Number(kerning.advance)
+            )?;
+        }
+
+        out.write_str("]}")
+    }
+
+    /// Gets atlas metadata using JSON layout of msdf-atlas-gen
+    ///
+    /// See [`Atlas::write_json`].
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out).unwrap();
+        out
+    }
+}
+
+fn write_bound<T: Display + Into<f64> + Copy>(out: &mut impl Write, bound: &Bound<T>) -> Result {
+    write!(
+        out,
+        r#"{{"left":{},"bottom":{},"right":{},"top":{}}}"#,
+        Number(bound.left),
+        Number(bound.bottom),
+        Number(bound.right),
+        Number(bound.top)
+    )
+}
+
+/// Number which is written as `null` when it is not finite
+struct Number<T>(T);
+
+impl<T: Display + Into<f64> + Copy> Display for Number<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.0.into().is_finite() {
+            self.0.fmt(f)
+        } else {
+            f.write_str("null")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AtlasGlyph, AtlasKerning, AtlasPlacement, Bitmap, FontMetrics, Rgb};
+
+    #[test]
+    fn json_layout() {
+        let atlas = Atlas::<Rgb<f32>> {
+            em_size: 32.0,
+            range: 2.0,
+            metrics: FontMetrics {
+                ascender: 1.0,
+                descender: -0.25,
+                line_height: 1.25,
+                underline_y: -0.125,
+                underline_thickness: 0.0625,
+            },
+            pages: vec![Bitmap::new(64, 32)],
+            glyphs: vec![
+                AtlasGlyph {
+                    chr: ' ',
+                    advance: 0.25,
+                    placement: None,
+                },
+                AtlasGlyph {
+                    chr: 'A',
+                    advance: 0.5,
+                    placement: Some(AtlasPlacement {
+                        page: 0,
+                        plane_bound: Bound::new(-0.0625, -0.0625, 0.5625, 0.8125),
+                        atlas_bound: Bound::new(0, 0, 20, 28),
+                    }),
+                },
+            ],
+            kerning: vec![AtlasKerning {
+                left: 'A',
+                right: ' ',
+                advance: -0.03125,
+            }],
+        };
+
+        assert_eq!(
+            atlas.to_json(),
+            concat!(
+                r#"{"atlas":{"type":"msdf","distanceRange":2,"size":32,"width":64,"height":32,"yOrigin":"bottom"},"#,
+                r#""metrics":{"emSize":1,"lineHeight":1.25,"ascender":1,"descender":-0.25,"underlineY":-0.125,"underlineThickness":0.0625},"#,
+                r#""glyphs":[{"unicode":32,"advance":0.25},"#,
+                r#"{"unicode":65,"advance":0.5,"planeBounds":{"left":-0.0625,"bottom":-0.0625,"right":0.5625,"top":0.8125},"atlasBounds":{"left":0,"bottom":0,"right":20,"top":28}}],"#,
+                r#""kerning":[{"unicode1":65,"unicode2":32,"advance":-0.03125}]}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn non_finite_numbers() {
+        let atlas = Atlas::<Rgb<f32>> {
+            em_size: 32.0,
+            range: f64::INFINITY,
+            metrics: FontMetrics {
+                ascender: 1.0,
+                descender: -0.25,
+                line_height: f64::NAN,
+                underline_y: 0.0,
+                underline_thickness: 0.0,
+            },
+            pages: Vec::new(),
+            glyphs: vec![AtlasGlyph {
+                chr: 'A',
+                advance: f64::NEG_INFINITY,
+                placement: Some(AtlasPlacement {
+                    page: 0,
+                    plane_bound: Bound::new(0.0, f64::NAN, 0.5, 0.75),
+                    atlas_bound: Bound::new(0, 0, 16, 24),
+                }),
+            }],
+            kerning: vec![AtlasKerning {
+                left: 'A',
+                right: 'A',
+                advance: f64::NAN,
+            }],
+        };
+
+        let json = atlas.to_json();
+        assert!(json.contains(r#""distanceRange":null,"#));
+        assert!(json.contains(r#""lineHeight":null,"#));
+        assert!(json.contains(r#""advance":null,"planeBounds":{"left":0,"bottom":null,"#));
+        assert!(json.contains(r#""unicode2":65,"advance":null}"#));
+        assert!(!json.contains("NaN") && !json.contains("inf"));
+    }
+}
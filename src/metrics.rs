@@ -0,0 +1,258 @@
+use crate::{
+    geometry::{contour_curves, cubic_derivative, cubic_point, Bezier},
+    Bound, Contour, FillRule, Point2, Shape,
+};
+
+/// Geometric summary of contour
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContourSummary {
+    /// Winding of contour (see [`Contour::winding`])
+    pub winding: i32,
+    /// Signed area of contour (see [`Contour::area`])
+    pub area: f64,
+    /// Length of contour
+    pub length: f64,
+    /// Tight bounding box of contour
+    pub bound: Bound<f64>,
+}
+
+impl Contour {
+    /// Computes the signed area enclosed by contour
+    ///
+    /// The area is positive when the winding of contour is positive.
+    /// Open contours are considered closed by straight line.
+    pub fn area(&self) -> f64 {
+        -moments(&contour_curves(self))[0]
+    }
+
+    /// Computes the length of contour
+    pub fn length(&self) -> f64 {
+        contour_curves(self).iter().map(curve_length).sum()
+    }
+
+    /// Gets the geometric summary of contour
+    pub fn summary(&self) -> ContourSummary {
+        ContourSummary {
+            winding: self.winding(),
+            area: self.area(),
+            length: self.length(),
+            bound: self.get_tight_bound(),
+        }
+    }
+}
+
+impl Shape {
+    /// Computes the area filled according to fill rule
+    ///
+    /// Overlapping parts are counted once. The area is negative when the shape
+    /// is inside out (i.e. total area of contours is negative).
+    pub fn area(&self, fill_rule: FillRule) -> f64 {
+        let area: f64 = self
+            .resolve_overlaps(fill_rule)
+            .contours()
+            .iter()
+            .map(Contour::area)
+            .sum();
+        let total: f64 = self.contours().iter().map(Contour::area).sum();
+        if total < 0.0 {
+            -area
+        } else {
+            area
+        }
+    }
+
+    /// Computes the total length of contours
+    pub fn perimeter(&self) -> f64 {
+        self.contours().iter().map(Contour::length).sum()
+    }
+
+    /// Computes the center of mass of the area filled according to fill rule
+    ///
+    /// Returns `None` when the filled area is empty.
+    pub fn centroid(&self, fill_rule: FillRule) -> Option<Point2<f64>> {
+        let [area, x, y] = self
+            .resolve_overlaps(fill_rule)
+            .contours()
+            .iter()
+            .map(|contour| moments(&contour_curves(contour)))
+            .fold([0.0; 3], |[a0, x0, y0], [a1, x1, y1]| {
+                [a0 + a1, x0 + x1, y0 + y1]
+            });
+
+        if area == 0.0 {
+            None
+        } else {
+            Some(Point2::new(x / area, y / area))
+        }
+    }
+
+    /// Gets the geometric summaries of contours
+    pub fn contour_summaries(&self) -> Vec<ContourSummary> {
+        self.contours().iter().map(Contour::summary).collect()
+    }
+}
+
+/// Nodes and weights of Gauss-Legendre quadrature on the interval from 0 to 1
+///
+/// The quadrature is exact for the polynomials up to 9th degree
+/// which is enough for the moments of cubic curves.
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.046910077030668, 0.118463442528095),
+    (0.230765344947158, 0.239314335249683),
+    (0.5, 0.284444444444444),
+    (0.769234655052842, 0.239314335249683),
+    (0.953089922969332, 0.118463442528095),
+];
+
+/// Computes the area and the first moments of area enclosed by curves
+///
+/// The moments are computed using Green's theorem, so the values are positive
+/// for counter-clockwise closed curves when Y axis points upward.
+fn moments(curves: &[Bezier]) -> [f64; 3] {
+    let mut result = [0.0; 3];
+
+    let mut add = |points: &[Point2<f64>; 4]| {
+        for (t, weight) in GAUSS_LEGENDRE {
+            let point = cubic_point(points, t);
+            let derivative = cubic_derivative(points, t);
+            result[0] += weight * 0.5 * point.cross(&derivative);
+            result[1] += weight * 0.5 * point.x * point.x * derivative.y;
+            result[2] -= weight * 0.5 * point.y * point.y * derivative.x;
+        }
+    };
+
+    for curve in curves {
+        add(&curve.to_cubic());
+    }
+
+    // close the gap of open contour
+    if let (Some(first), Some(last)) = (curves.first(), curves.last()) {
+        if first.start() != last.end() {
+            add(&Bezier::Linear([last.end(), first.start()]).to_cubic());
+        }
+    }
+
+    result
+}
+
+/// Computes the length of curve
+///
+/// The length is approximated by Gravesen's method using recursive subdivision
+/// until the estimates of curve and its halves agree.
+fn curve_length(curve: &Bezier) -> f64 {
+    fn estimate(curve: &Bezier) -> f64 {
+        let points = curve.points();
+        let chord = (curve.end() - curve.start()).length();
+        let polygon: f64 = points
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).length())
+            .sum();
+        let degree = (points.len() - 1) as f64;
+        (2.0 * chord + (degree - 1.0) * polygon) / (degree + 1.0)
+    }
+
+    fn length(curve: &Bezier, whole: f64, depth: u32) -> f64 {
+        let (head, tail) = curve.split(0.5);
+        let head_length = estimate(&head);
+        let tail_length = estimate(&tail);
+        let halves = head_length + tail_length;
+
+        if depth == 0 || (halves - whole).abs() <= halves * 1e-6 {
+            // the error of estimate decreases 16 times with each subdivision
+            return halves + (halves - whole) / 15.0;
+        }
+
+        length(&head, head_length, depth - 1) + length(&tail, tail_length, depth - 1)
+    }
+
+    if let Bezier::Linear(_) = curve {
+        return estimate(curve);
+    }
+
+    length(curve, estimate(curve), 10)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::f64::consts::PI;
+
+    fn assert_near(a: f64, b: f64, epsilon: f64) {
+        assert!((a - b).abs() <= epsilon, "{a} != {b}");
+    }
+
+    #[test]
+    fn polygons() {
+        let square = Shape::from_svg_path_data("M2 3 L2 13 L12 13 L12 3 Z").unwrap();
+        let contour = &square.contours()[0];
+        assert_near(contour.area(), 100.0 * contour.winding() as f64, 1e-9);
+        assert_near(square.area(FillRule::NonZero), contour.area(), 1e-9);
+        assert_near(square.perimeter(), 40.0, 1e-9);
+        let centroid = square.centroid(FillRule::NonZero).unwrap();
+        assert_near(centroid.x, 7.0, 1e-9);
+        assert_near(centroid.y, 8.0, 1e-9);
+
+        let reversed = Shape::from_svg_path_data("M2 3 L12 3 L12 13 L2 13 Z").unwrap();
+        assert_near(reversed.contours()[0].area(), -contour.area(), 1e-9);
+
+        // overlapping contours
+        let squares =
+            Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z M5 5 L5 15 L15 15 L15 5 Z")
+                .unwrap();
+        assert_near(squares.area(FillRule::NonZero).abs(), 175.0, 1e-6);
+        assert_near(squares.area(FillRule::Odd).abs(), 150.0, 1e-6);
+        let centroid = squares.centroid(FillRule::NonZero).unwrap();
+        assert_near(centroid.x, 7.5, 1e-6);
+        assert_near(centroid.y, 7.5, 1e-6);
+
+        assert_eq!(Shape::default().centroid(FillRule::NonZero), None);
+    }
+
+    #[test]
+    fn curves() {
+        let circle =
+            Shape::from_svg_path_data("M-10 0 A10 10 0 0 0 10 0 A10 10 0 0 0 -10 0 Z").unwrap();
+        assert_near(circle.contours()[0].area().abs(), PI * 100.0, 0.1);
+        assert_near(circle.perimeter(), PI * 20.0, 0.01);
+
+        let centroid = circle.centroid(FillRule::NonZero).unwrap();
+        assert_near(centroid.x, 0.0, 1e-9);
+        assert_near(centroid.y, 0.0, 1e-9);
+    }
+
+    #[test]
+    fn summaries() {
+        let frame =
+            Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z M3 3 L7 3 L7 7 L3 7 Z").unwrap();
+        let summaries = frame.contour_summaries();
+        assert_eq!(summaries.len(), 2);
+
+        assert_eq!(summaries[0].winding, -summaries[1].winding);
+        assert_near(summaries[0].area.abs(), 100.0, 1e-9);
+        assert_near(summaries[1].area.abs(), 16.0, 1e-9);
+        assert_near(summaries[1].length, 16.0, 1e-9);
+        assert_eq!(summaries[1].bound, Bound::new(3.0, 3.0, 7.0, 7.0));
+        assert_near(frame.area(FillRule::NonZero).abs(), 84.0, 1e-9);
+    }
+
+    #[test]
+    fn curve_length_precision() {
+        let curve = Bezier::Cubic([
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 8.0),
+            Point2::new(9.0, -4.0),
+            Point2::new(10.0, 3.0),
+        ]);
+
+        let steps = 100000;
+        let polyline: f64 = (0..steps)
+            .map(|step| {
+                let a = curve.point(step as f64 / steps as f64);
+                let b = curve.point((step + 1) as f64 / steps as f64);
+                (b - a).length()
+            })
+            .sum();
+
+        assert_near(curve_length(&curve), polyline, polyline * 1e-6);
+    }
+}
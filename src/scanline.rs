@@ -2,6 +2,7 @@ use crate::ffi;
 
 /// Fill rule dictates how intersection total is interpreted during rasterization
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum FillRule {
     Negative = ffi::msdfgen_FillRule_FILL_NEGATIVE,
@@ -21,6 +22,16 @@ impl FillRule {
         self as _
     }
 
+    /// Interprets total sign of intersections (the winding number)
+    pub(crate) fn filled(self, winding: i32) -> bool {
+        match self {
+            FillRule::Negative => winding < 0,
+            FillRule::NonZero => winding != 0,
+            FillRule::Odd => winding & 1 != 0,
+            FillRule::Positive => winding > 0,
+        }
+    }
+
     /*pub(crate) fn from_raw(raw: u32) -> Self {
         unsafe { core::mem::transmute(raw) }
     }*/
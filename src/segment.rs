@@ -1,4 +1,4 @@
-use crate::{ffi, EdgeColor, Point2};
+use crate::{ffi, Affine2, EdgeColor, Point2};
 
 /// Segment kind
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -42,6 +42,17 @@ pub trait EdgeSegment {
     fn edge_color_mut(&mut self) -> &mut EdgeColor;
 
     fn into_raw(self) -> *mut ffi::msdfgen_EdgeSegment;
+
+    /// Transforms all points of segment
+    fn transform(&mut self, affine: &Affine2) {
+        *self.start_point_mut() = affine.transform_point(*self.start_point());
+        for num in 0..2 {
+            if let Some(point) = self.control_point_mut(num) {
+                *point = affine.transform_point(*point);
+            }
+        }
+        *self.end_point_mut() = affine.transform_point(*self.end_point());
+    }
 }
 
 /// Linear segment object
@@ -1,11 +1,20 @@
 use crate::{ffi, Bound, Contour, Polarity, Scanline};
 
 /// Shape object
+///
+/// Shape exclusively owns its contours, so it can be sent to other threads.
+/// Methods which take `&self` never modify the underlying C++ object,
+/// so the shape can be shared between threads to generate several distance fields at once.
 #[repr(transparent)]
 pub struct Shape {
     raw: ffi::msdfgen_Shape,
 }
 
+// SAFETY: `std::vector<Contour> contours` and `bool inverseYAxis` are owned by value,
+// and generators only take `const Shape &`.
+unsafe impl Send for Shape {}
+unsafe impl Sync for Shape {}
+
 impl Default for Shape {
     /// Create new blanked shape with no contours
     fn default() -> Self {
@@ -35,6 +44,40 @@ impl Shape {
         unsafe { &mut *(self.raw.addContour2() as *mut Contour) }
     }
 
+    /// Gets the contours of the shape
+    pub fn contours(&self) -> &[Contour] {
+        let len = unsafe { ffi::msdfgen_Shape_contours_len(&self.raw) };
+        if len == 0 {
+            &[]
+        } else {
+            let data = unsafe { ffi::msdfgen_Shape_contours_data(&self.raw) };
+            unsafe { core::slice::from_raw_parts(data as *const Contour, len) }
+        }
+    }
+
+    /// Gets the contours of the shape (mutable)
+    pub fn contours_mut(&mut self) -> &mut [Contour] {
+        let len = unsafe { ffi::msdfgen_Shape_contours_len(&self.raw) };
+        if len == 0 {
+            &mut []
+        } else {
+            let data = unsafe { ffi::msdfgen_Shape_contours_data(&self.raw) };
+            unsafe { core::slice::from_raw_parts_mut(data as *mut Contour, len) }
+        }
+    }
+
+    /// Get whether the Y axis of the shape points downward
+    pub fn get_inverse_y_axis(&self) -> bool {
+        self.raw.inverseYAxis
+    }
+
+    /// Set whether the Y axis of the shape points downward
+    ///
+    /// When set, rows of generated bitmaps are flipped vertically.
+    pub fn set_inverse_y_axis(&mut self, inverse_y_axis: bool) {
+        self.raw.inverseYAxis = inverse_y_axis;
+    }
+
     /// Normalizes the shape geometry for distance field generation
     pub fn normalize(&mut self) {
         unsafe {
@@ -66,6 +109,16 @@ impl Shape {
         bound
     }
 
+    /// Gets the tight bounding box of the shape
+    ///
+    /// Unlike [`Shape::get_bound`] the origin is not included.
+    /// The bounding box is empty when shape has no edges.
+    pub fn get_tight_bound(&self) -> Bound<f64> {
+        let mut bound = Bound::empty();
+        self.bound(&mut bound);
+        bound
+    }
+
     /// Adjusts the bounding box to fit the shape border's mitered corners
     pub fn bound_miters(
         &self,
@@ -115,3 +168,68 @@ impl Shape {
         unsafe { ffi::msdfgen_edgeColoringSimple(&mut self.raw, angle_threshold, seed) }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::{
+        DistanceField, EdgeColor, EdgeHolder, FieldKind, FillRule, Generator, LinearSegment, Rgb,
+    };
+
+    /// Makes square shape with clockwise contour
+    pub fn square(left: f64, bottom: f64, size: f64) -> Shape {
+        let (right, top) = (left + size, bottom + size);
+        let points = [(left, bottom), (left, top), (right, top), (right, bottom)];
+        let mut contour = Contour::default();
+        for (index, point) in points.iter().enumerate() {
+            let next_point = points[(index + 1) % points.len()];
+            contour.add_segment(LinearSegment::new(*point, next_point, EdgeColor::default()));
+        }
+        let mut shape = Shape::default();
+        shape.add_contour(&contour);
+        shape
+    }
+
+    #[test]
+    fn tight_bound() {
+        let shape = square(2.0, 3.0, 4.0);
+        assert_eq!(shape.get_bound(), Bound::new(0.0, 0.0, 6.0, 7.0));
+        assert_eq!(shape.get_tight_bound(), Bound::new(2.0, 3.0, 6.0, 7.0));
+
+        let contour = Contour::default();
+        assert!(contour.get_tight_bound().is_empty());
+        assert!(Shape::default().get_tight_bound().is_empty());
+        assert!(!shape.get_tight_bound().is_empty());
+    }
+
+    #[test]
+    fn thread_safety() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Shape>();
+        assert_send_sync::<Contour>();
+        assert_send_sync::<EdgeHolder>();
+        assert_send_sync::<Generator>();
+        assert_send_sync::<DistanceField>();
+
+        let mut shape = Shape::from_svg_path_data("M1 1 L1 9 Q5 12 9 9 L9 1 Z").unwrap();
+        let generator = Generator::default().with_size(16, 16);
+        let (expected, framing) = generator.generate::<Rgb<f32>>(&mut shape).unwrap();
+
+        std::thread::scope(|scope| {
+            let threads: Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        shape.generate(FieldKind::Msdf, 16, 16, framing, generator.get_config())
+                    })
+                })
+                .collect();
+
+            for thread in threads {
+                let mut field = thread.join().unwrap();
+                field.correct_sign(&shape, framing, FillRule::NonZero);
+                field.correct_msdf_error(&shape, framing, generator.get_config());
+                assert_eq!(field.raw_pixels(), expected.raw_pixels());
+            }
+        });
+    }
+}
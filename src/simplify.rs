@@ -0,0 +1,420 @@
+use crate::{
+    geometry::{contour_curves, cubic_derivative, cubic_point, Bezier},
+    Point2, Shape, ShapeBuilder, Vector2,
+};
+
+/// The angle threshold used to detect corners (the default of edge coloring)
+const CORNER_ANGLE_THRESHOLD: f64 = 3.0;
+
+impl Shape {
+    /// Creates simplified shape
+    ///
+    /// Edges which are shorter than `tolerance` are dropped and runs of collinear lines are merged
+    /// when the removed points deviate from resulting line no more than `tolerance`.
+    ///
+    /// When `fit_curves` is set runs of lines between corners are approximated by cubic curves
+    /// within `tolerance`. Corners (as detected by edge coloring with the default angle threshold)
+    /// are always kept so the result may be colored in the same way.
+    ///
+    /// Edge colors are not preserved so edge coloring should be applied to the result.
+    pub fn simplify(&self, tolerance: f64, fit_curves: bool) -> Shape {
+        let mut builder = ShapeBuilder::new();
+
+        for contour in self.contours() {
+            let curves = merge_lines(drop_short(contour_curves(contour), tolerance), tolerance);
+            let curves = if fit_curves {
+                fit_lines(curves, tolerance)
+            } else {
+                curves
+            };
+
+            if let Some(first) = curves.first() {
+                builder.move_to(first.start());
+                for curve in &curves {
+                    curve.add_to(&mut builder);
+                }
+                builder.close();
+            }
+        }
+
+        let mut shape = builder.build();
+        shape.set_inverse_y_axis(self.get_inverse_y_axis());
+        shape
+    }
+}
+
+/// Checks that the connection of curves with directions is a corner like edge coloring does
+fn is_corner(incoming: Vector2<f64>, outgoing: Vector2<f64>) -> bool {
+    let incoming = incoming.normalize(false);
+    let outgoing = outgoing.normalize(false);
+    incoming.dot(&outgoing) <= 0.0 || incoming.cross(&outgoing).abs() > CORNER_ANGLE_THRESHOLD.sin()
+}
+
+/// Removes curves which fit in tolerance keeping the contour connected
+fn drop_short(curves: Vec<Bezier>, tolerance: f64) -> Vec<Bezier> {
+    let mut result: Vec<Bezier> = Vec::with_capacity(curves.len());
+
+    for mut curve in curves {
+        let start = curve.start();
+        if curve
+            .points()
+            .iter()
+            .all(|point| (*point - start).length() <= tolerance)
+        {
+            // the next curve starts at the start of dropped one
+            continue;
+        }
+        if let Some(last) = result.last() {
+            curve.set_ends(last.end(), curve.end());
+        }
+        result.push(curve);
+    }
+
+    // close the gap left at the start of contour
+    if let (Some(first), Some(last)) = (result.first(), result.last()) {
+        let (start, end) = (first.start(), last.end());
+        if start != end {
+            let last = result.last_mut().unwrap();
+            let last_start = last.start();
+            last.set_ends(last_start, start);
+        }
+    }
+
+    result
+}
+
+/// Rotates closed contour so that it starts at a corner when it has corners
+fn start_at_corner(curves: &mut [Bezier]) {
+    let count = curves.len();
+    if let Some(index) = (0..count).find(|index| {
+        is_corner(
+            curves[(index + count - 1) % count].end_direction(),
+            curves[*index].start_direction(),
+        )
+    }) {
+        curves.rotate_left(index);
+    }
+}
+
+/// Merges runs of collinear lines
+fn merge_lines(mut curves: Vec<Bezier>, tolerance: f64) -> Vec<Bezier> {
+    start_at_corner(&mut curves);
+
+    let mut result: Vec<Bezier> = Vec::with_capacity(curves.len());
+    // the points of the last line in result
+    let mut run: Vec<Point2<f64>> = Vec::new();
+
+    for curve in curves {
+        if let Bezier::Linear([_, end]) = curve {
+            if !run.is_empty() && fits_line(&run, end, tolerance) {
+                run.push(end);
+                if let Some(Bezier::Linear(line)) = result.last_mut() {
+                    line[1] = end;
+                }
+                continue;
+            }
+            run = curve.points().to_vec();
+        } else {
+            run.clear();
+        }
+        result.push(curve);
+    }
+
+    result
+}
+
+/// Checks that the points of run are near the line from its start to the end
+fn fits_line(run: &[Point2<f64>], end: Point2<f64>, tolerance: f64) -> bool {
+    let start = run[0];
+    let direction = end - start;
+    let length = direction.length();
+    length > 0.0
+        && run[1..].iter().all(|point| {
+            let offset = *point - start;
+            let along = offset.dot(&direction) / length;
+            along > 0.0 && along < length && (direction.cross(&offset) / length).abs() <= tolerance
+        })
+}
+
+/// Replaces runs of smoothly connected lines by curves
+fn fit_lines(curves: Vec<Bezier>, tolerance: f64) -> Vec<Bezier> {
+    let mut result = Vec::with_capacity(curves.len());
+    let mut run: Vec<Point2<f64>> = Vec::new();
+
+    let flush = |run: &mut Vec<Point2<f64>>, result: &mut Vec<Bezier>| {
+        if run.len() > 2 {
+            // estimate tangents at ends assuming constant curvature
+            let tangent = |a: Point2<f64>, b: Point2<f64>, c: Point2<f64>| {
+                (b - a).normalize(false) * 1.5 - (c - b).normalize(false) * 0.5
+            };
+            let last = run.len() - 1;
+            let tangents = (
+                tangent(run[0], run[1], run[2]),
+                tangent(run[last], run[last - 1], run[last - 2]),
+            );
+            let mut fitted = Vec::new();
+            fit_cubic(run, tangents, tolerance, &mut fitted);
+            if fitted.len() < run.len() - 1 {
+                result.extend(fitted);
+                run.clear();
+            }
+        }
+        result.extend(
+            run.windows(2)
+                .map(|line| Bezier::Linear([line[0], line[1]])),
+        );
+        run.clear();
+    };
+
+    for curve in curves {
+        match curve {
+            Bezier::Linear([start, end]) => {
+                let smooth = run.len() > 1
+                    && !is_corner(run[run.len() - 1] - run[run.len() - 2], end - start);
+                if !smooth {
+                    flush(&mut run, &mut result);
+                    run.push(start);
+                }
+                run.push(end);
+            }
+            _ => {
+                flush(&mut run, &mut result);
+                result.push(curve);
+            }
+        }
+    }
+    flush(&mut run, &mut result);
+
+    result
+}
+
+/// Fits cubic curves to points with given end tangents
+///
+/// This is the algorithm by Philip J. Schneider from Graphics Gems.
+fn fit_cubic(
+    points: &[Point2<f64>],
+    (start_tangent, end_tangent): (Vector2<f64>, Vector2<f64>),
+    tolerance: f64,
+    result: &mut Vec<Bezier>,
+) {
+    let start_tangent = start_tangent.normalize(false);
+    let end_tangent = end_tangent.normalize(false);
+    let (first, last) = (points[0], points[points.len() - 1]);
+
+    if points.len() == 2 {
+        result.push(Bezier::Linear([first, last]));
+        return;
+    }
+
+    // chord length parametrization
+    let mut params = Vec::with_capacity(points.len());
+    let mut length = 0.0;
+    params.push(0.0);
+    for pair in points.windows(2) {
+        length += (pair[1] - pair[0]).length();
+        params.push(length);
+    }
+    for param in &mut params {
+        *param /= length;
+    }
+
+    let mut curve = fit_tangents(points, &params, start_tangent, end_tangent);
+    let mut error = max_error(&curve, points, &params);
+
+    // improve parametrization when the error is not too big
+    for _ in 0..4 {
+        if error.1 <= tolerance || error.1 > tolerance * 4.0 {
+            break;
+        }
+        for (param, point) in params.iter_mut().zip(points) {
+            *param = newton_step(&curve, *point, *param);
+        }
+        curve = fit_tangents(points, &params, start_tangent, end_tangent);
+        error = max_error(&curve, points, &params);
+    }
+
+    let (index, error) = error;
+    if error <= tolerance {
+        result.push(Bezier::Cubic(curve));
+        return;
+    }
+
+    // split at the point of max error
+    let index = index.clamp(1, points.len() - 2);
+    let tangent = points[index - 1] - points[index + 1];
+    fit_cubic(
+        &points[..=index],
+        (start_tangent, tangent),
+        tolerance,
+        result,
+    );
+    fit_cubic(&points[index..], (-tangent, end_tangent), tolerance, result);
+}
+
+/// Finds the index of point with max distance from curve and the distance
+fn max_error(curve: &[Point2<f64>; 4], points: &[Point2<f64>], params: &[f64]) -> (usize, f64) {
+    points
+        .iter()
+        .zip(params)
+        .map(|(point, t)| (cubic_point(curve, *t) - *point).length())
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .unwrap()
+}
+
+/// Finds better parameter of the point of curve which is nearest to the given point
+fn newton_step(curve: &[Point2<f64>; 4], point: Point2<f64>, t: f64) -> f64 {
+    let [p0, p1, p2, p3] = *curve;
+    let offset = cubic_point(curve, t) - point;
+    let first = cubic_derivative(curve, t);
+    let second = ((p2 - p1 * 2.0 + p0) * (1.0 - t) + (p3 - p2 * 2.0 + p1) * t) * 6.0;
+    let denominator = first.dot(&first) + offset.dot(&second);
+    if denominator == 0.0 {
+        t
+    } else {
+        t - offset.dot(&first) / denominator
+    }
+}
+
+/// Finds lengths of handles by least squares
+fn fit_tangents(
+    points: &[Point2<f64>],
+    params: &[f64],
+    start_tangent: Vector2<f64>,
+    end_tangent: Vector2<f64>,
+) -> [Point2<f64>; 4] {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let mut c = [[0.0; 2]; 2];
+    let mut x = [0.0; 2];
+
+    for (point, t) in points.iter().zip(params) {
+        let s = 1.0 - t;
+        let a0 = start_tangent * (3.0 * s * s * t);
+        let a1 = end_tangent * (3.0 * s * t * t);
+        c[0][0] += a0.dot(&a0);
+        c[0][1] += a0.dot(&a1);
+        c[1][1] += a1.dot(&a1);
+
+        let base = cubic_point(&[first, first, last, last], *t);
+        let offset = *point - base;
+        x[0] += a0.dot(&offset);
+        x[1] += a1.dot(&offset);
+    }
+    c[1][0] = c[0][1];
+
+    let det = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+    let chord = (last - first).length();
+    let (mut alpha0, mut alpha1) = if det.abs() > 1e-12 {
+        (
+            (x[0] * c[1][1] - x[1] * c[0][1]) / det,
+            (c[0][0] * x[1] - c[1][0] * x[0]) / det,
+        )
+    } else {
+        (0.0, 0.0)
+    };
+
+    // fallback to heuristic when handles are degenerate
+    if alpha0 < chord * 1e-6 || alpha1 < chord * 1e-6 {
+        alpha0 = chord / 3.0;
+        alpha1 = chord / 3.0;
+    }
+
+    [
+        first,
+        first + start_tangent * alpha0,
+        last + end_tangent * alpha1,
+        last,
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SegmentKind;
+    use core::f64::consts::PI;
+
+    fn polyline(points: impl IntoIterator<Item = (f64, f64)>) -> Shape {
+        let mut builder = ShapeBuilder::new();
+        let mut points = points.into_iter();
+        builder.move_to(points.next().unwrap());
+        for point in points {
+            builder.line_to(point);
+        }
+        builder.close();
+        builder.build()
+    }
+
+    #[test]
+    fn collinear_lines() {
+        let side = |from: (f64, f64), to: (f64, f64)| {
+            (0..10).map(move |step| {
+                let t = step as f64 / 10.0;
+                (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t)
+            })
+        };
+        let shape = polyline(
+            side((0.0, 0.0), (0.0, 10.0))
+                .chain([(0.0, 10.0), (1e-9, 10.0)])
+                .chain(side((0.0, 10.0), (10.0, 10.0)))
+                .chain(side((10.0, 10.0), (10.0, 0.0)))
+                .chain(side((10.0, 0.0), (5.0, 0.0)))
+                .chain([(2.0, 0.001)]),
+        );
+        let simple = shape.simplify(1e-6, false);
+        assert_eq!(simple.contours().len(), 1);
+        assert!(simple.validate());
+        assert_eq!(simple.get_tight_bound(), shape.get_tight_bound());
+        // the deviated point is kept
+        assert_eq!(simple.contours()[0].edges().len(), 6);
+
+        let simple = shape.simplify(0.01, false);
+        assert_eq!(simple.contours()[0].edges().len(), 4);
+        assert_eq!(
+            simple.contours()[0].winding(),
+            shape.contours()[0].winding()
+        );
+    }
+
+    #[test]
+    fn curve_fitting() {
+        // half disk with arc approximated by lines
+        let shape = polyline(
+            (0..=64)
+                .map(|step| {
+                    let angle = PI * step as f64 / 64.0;
+                    (-10.0 * angle.cos(), 10.0 * angle.sin())
+                })
+                .chain([(0.0, 0.0)]),
+        );
+
+        let simple = shape.simplify(0.01, true);
+        assert!(simple.validate());
+
+        let edges = simple.contours()[0].edges();
+        assert!(edges.len() < 8);
+        assert_eq!(
+            edges
+                .iter()
+                .filter(|edge| edge.segment_kind() == SegmentKind::Linear)
+                .count(),
+            1
+        );
+
+        // corners are kept
+        for corner in [Point2::new(-10.0, 0.0), Point2::new(10.0, 0.0)] {
+            assert!(edges
+                .iter()
+                .any(|edge| (*edge.start_point() - corner).length() < 1e-9));
+        }
+
+        let bound = simple.get_tight_bound();
+        assert!((bound.top - 10.0).abs() < 0.02);
+        assert!((bound.left + 10.0).abs() < 1e-9 && (bound.right - 10.0).abs() < 1e-9);
+
+        // without fitting lines are kept
+        assert_eq!(
+            shape.simplify(0.01, false).contours()[0].edges().len(),
+            shape.contours()[0].edges().len() - 1
+        );
+    }
+}
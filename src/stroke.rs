@@ -0,0 +1,560 @@
+use crate::{
+    geometry::{contour_curves, cubic_derivative, cubic_segment, scanline_sum, Bezier},
+    FillRule, Point2, Shape, ShapeBuilder, Vector2,
+};
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_8, PI};
+
+/// Shape of corners between edges of stroke outline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineJoin {
+    /// Sharp corner which is beveled when miter limit is exceeded
+    #[default]
+    Miter,
+    /// Rounded corner
+    Round,
+    /// Corner cut by straight line
+    Bevel,
+}
+
+/// Shape of ends of stroke outline for open contours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineCap {
+    /// Stroke ends exactly at end point
+    #[default]
+    Butt,
+    /// Stroke ends with half circle
+    Round,
+    /// Stroke is extended by half of width beyond end point
+    Square,
+}
+
+impl Shape {
+    /// Creates shape which outlines the stroke of this shape
+    ///
+    /// Closed contours produce the pair of outer and inner outlines while open contours
+    /// (which end point differs from start point) produce single outline with caps at both ends.
+    /// The `miter_limit` is the maximum distance of miter tip from corner in halves of width
+    /// (like in [`Shape::bound_miters`]).
+    ///
+    /// Curves are approximated by cubic segments. Outlines of strongly curved parts may
+    /// overlap each other so the resulting shape should be generated with overlap support.
+    pub fn stroke(&self, width: f64, join: LineJoin, cap: LineCap, miter_limit: f64) -> Shape {
+        let half_width = width * 0.5;
+        let mut outliner = Outliner::new(join, miter_limit);
+
+        if half_width <= 0.0 {
+            return outliner.builder.build();
+        }
+
+        for contour in self.contours() {
+            let edges = contour_curves(contour);
+            let reversed: Vec<_> = edges.iter().rev().map(Bezier::reversed).collect();
+            let (first, last) = if let (Some(first), Some(last)) = (edges.first(), edges.last()) {
+                (first, last)
+            } else {
+                continue;
+            };
+
+            if first.start() == last.end() {
+                outliner.begin(&edges[0], half_width);
+                outliner.edges(&edges, half_width, true);
+                outliner.finish(true);
+
+                outliner.begin(&reversed[0], half_width);
+                outliner.edges(&reversed, half_width, true);
+                outliner.finish(true);
+            } else {
+                outliner.begin(first, half_width);
+                outliner.edges(&edges, half_width, false);
+                outliner.cap(last.end(), last.end_direction(), half_width, cap);
+                outliner.edges(&reversed, half_width, false);
+                outliner.cap(first.start(), -first.start_direction(), half_width, cap);
+                outliner.finish(false);
+            }
+        }
+
+        outliner.builder.build()
+    }
+
+    /// Creates shape which outline is moved outward by distance
+    ///
+    /// Positive distance expands the filled area (like synthetic bold) and negative one shrinks it.
+    /// Every contour is considered closed and offset along its normals with corners joined
+    /// according to `join` (miters are limited to 4 distances like in SVG).
+    ///
+    /// The outward side is determined for each contour separately so the orientation of contours
+    /// doesn't matter. Overlaps and the parts which vanish when shrinking are removed from the result.
+    pub fn offset(&self, distance: f64, join: LineJoin) -> Shape {
+        let contours: Vec<_> = self.contours().iter().map(contour_curves).collect();
+        let curves: Vec<_> = contours.iter().flatten().copied().collect();
+        let epsilon = 1e-5 * {
+            let bound = self.get_bound();
+            (bound.right - bound.left).max(bound.top - bound.bottom)
+        };
+        let mut outliner = Outliner::new(join, 4.0);
+
+        for edges in contours {
+            let first = if let Some(first) = edges.first() {
+                first
+            } else {
+                continue;
+            };
+
+            // filled area should be on the right side of edges
+            let point =
+                first.point(0.5) + first.direction(0.5).get_orthonormal(true, false) * epsilon;
+            let edges = if FillRule::NonZero.filled(scanline_sum(&curves, point)) {
+                edges.iter().rev().map(Bezier::reversed).collect()
+            } else {
+                edges
+            };
+
+            outliner.begin(&edges[0], distance);
+            outliner.edges(&edges, distance, true);
+            outliner.finish(true);
+        }
+
+        let mut shape = outliner
+            .builder
+            .build()
+            .resolve_overlaps(FillRule::Positive);
+        shape.set_inverse_y_axis(self.get_inverse_y_axis());
+        shape
+    }
+}
+
+/// Moves point by distance along the left normal of direction
+fn offset_point(point: Point2<f64>, direction: Vector2<f64>, distance: f64) -> Point2<f64> {
+    point + direction.get_orthonormal(true, false) * distance
+}
+
+/// Finds intersection of line segments
+fn line_intersection(a: [Point2<f64>; 2], b: [Point2<f64>; 2]) -> Option<Point2<f64>> {
+    let da = a[1] - a[0];
+    let db = b[1] - b[0];
+    let denom = da.cross(&db);
+    if denom.abs() <= f64::EPSILON * da.length() * db.length() {
+        return None;
+    }
+    let offset = b[0] - a[0];
+    let s = offset.cross(&db) / denom;
+    let t = offset.cross(&da) / denom;
+    if s > 0.0 && s <= 1.0 && (0.0..1.0).contains(&t) {
+        Some(a[0] + da * s)
+    } else {
+        None
+    }
+}
+
+/// Builder of offset outlines
+struct Outliner {
+    builder: ShapeBuilder,
+    join: LineJoin,
+    miter_limit: f64,
+    pieces: Vec<Bezier>,
+    point: Point2<f64>,
+    /// The corner of pending inner join
+    pivot: Option<Point2<f64>>,
+}
+
+impl Outliner {
+    fn new(join: LineJoin, miter_limit: f64) -> Self {
+        Self {
+            builder: ShapeBuilder::new(),
+            join,
+            miter_limit,
+            pieces: Vec::new(),
+            point: Point2::default(),
+            pivot: None,
+        }
+    }
+
+    /// Starts outline at the offset start of curve
+    fn begin(&mut self, curve: &Bezier, distance: f64) {
+        self.point = offset_point(curve.start(), curve.start_direction(), distance);
+    }
+
+    /// Adds outline to the builder
+    fn finish(&mut self, closed: bool) {
+        if let Some(pivot) = self.pivot.take() {
+            if closed && self.pieces.len() > 1 {
+                let mut first = self.pieces.remove(0);
+                self.connect(pivot, &mut first);
+                self.pieces.insert(0, first);
+            }
+        }
+
+        if let Some(first) = self.pieces.first() {
+            self.builder.move_to(first.start());
+        }
+
+        for piece in self.pieces.drain(..) {
+            piece.add_to(&mut self.builder);
+        }
+
+        self.builder.close();
+    }
+
+    fn push(&mut self, mut piece: Bezier) {
+        if let Some(pivot) = self.pivot.take() {
+            self.connect(pivot, &mut piece);
+        }
+        self.point = piece.end();
+        self.pieces.push(piece);
+    }
+
+    /// Connects last piece with next one at inner corner
+    ///
+    /// Linear pieces are trimmed at their intersection. Otherwise the pieces are connected
+    /// through the corner point which produces small overlapping loop.
+    fn connect(&mut self, pivot: Point2<f64>, next: &mut Bezier) {
+        if let (Some(Bezier::Linear(last_line)), Bezier::Linear(next_line)) =
+            (self.pieces.last_mut(), &mut *next)
+        {
+            if let Some(point) = line_intersection(*last_line, *next_line) {
+                last_line[1] = point;
+                next_line[0] = point;
+                return;
+            }
+        }
+
+        let start = self.pieces.last().map(Bezier::end).unwrap_or(self.point);
+        let end = next.start();
+        self.pieces.push(Bezier::Linear([start, pivot]));
+        self.pieces.push(Bezier::Linear([pivot, end]));
+    }
+
+    fn line_to(&mut self, point: Point2<f64>) {
+        if point != self.point {
+            self.push(Bezier::Linear([self.point, point]));
+        }
+    }
+
+    /// Adds circular arc around center starting at current point
+    fn arc(&mut self, center: Point2<f64>, sweep: f64) {
+        let pieces = (sweep.abs() / FRAC_PI_2).ceil().max(1.0);
+        let step = sweep / pieces;
+        let handle = 4.0 / 3.0 * (step / 4.0).tan();
+        let (sin, cos) = step.sin_cos();
+        let perpendicular = |vector: Vector2<f64>| Vector2::new(-vector.y, vector.x);
+
+        let mut from = self.point - center;
+        for _ in 0..pieces as usize {
+            let to = Vector2::new(from.x * cos - from.y * sin, from.x * sin + from.y * cos);
+            self.push(Bezier::Cubic([
+                center + from,
+                center + from + perpendicular(from) * handle,
+                center + to - perpendicular(to) * handle,
+                center + to,
+            ]));
+            from = to;
+        }
+    }
+
+    /// Adds curves offset by distance to the left side
+    ///
+    /// The curves are joined with each other and the last curve is joined with the first one
+    /// when the outline is closed.
+    fn edges(&mut self, curves: &[Bezier], distance: f64, closed: bool) {
+        for (index, curve) in curves.iter().enumerate() {
+            self.line_to(offset_point(
+                curve.start(),
+                curve.start_direction(),
+                distance,
+            ));
+            self.edge(curve, distance);
+
+            let next = if index + 1 < curves.len() {
+                curves.get(index + 1)
+            } else if closed {
+                curves.first()
+            } else {
+                None
+            };
+
+            if let Some(next) = next {
+                self.corner(
+                    curve.end(),
+                    curve.end_direction(),
+                    next.start_direction(),
+                    distance,
+                );
+            }
+        }
+    }
+
+    fn edge(&mut self, curve: &Bezier, distance: f64) {
+        if let Bezier::Linear([p0, p1]) = *curve {
+            self.line_to(offset_point(p1, p1 - p0, distance));
+            return;
+        }
+
+        let cubic = curve.to_cubic();
+
+        // split curve so that each piece turns by small angle
+        let mut turning = 0.0;
+        let mut direction: Option<Vector2<f64>> = None;
+        for step in 0..=16 {
+            let derivative = cubic_derivative(&cubic, step as f64 / 16.0);
+            if derivative == Vector2::default() {
+                continue;
+            }
+            if let Some(direction) = direction {
+                turning += direction
+                    .cross(&derivative)
+                    .atan2(direction.dot(&derivative))
+                    .abs();
+            }
+            direction = Some(derivative);
+        }
+        let pieces = (turning / FRAC_PI_8).ceil().clamp(1.0, 64.0) as usize;
+
+        for piece in 0..pieces {
+            let t0 = piece as f64 / pieces as f64;
+            let t1 = (piece + 1) as f64 / pieces as f64;
+            self.cubic(&cubic_segment(&cubic, t0, t1), distance);
+        }
+    }
+
+    /// Adds offset approximation of cubic curve
+    ///
+    /// The handles are scaled according to the curvature at the ends of curve.
+    fn cubic(&mut self, points: &[Point2<f64>; 4], distance: f64) {
+        let [p0, p1, p2, p3] = *points;
+        let curve = Bezier::Cubic(*points);
+
+        let handle_scale = |d1: Vector2<f64>, d2: Vector2<f64>| {
+            let length = d1.length();
+            if length == 0.0 {
+                return 1.0;
+            }
+            let curvature = d1.cross(&d2) / (length * length * length);
+            (1.0 - distance * curvature).max(0.0)
+        };
+
+        let start_scale = handle_scale((p1 - p0) * 3.0, (p2 - p1 * 2.0 + p0) * 6.0);
+        let end_scale = handle_scale((p3 - p2) * 3.0, (p3 - p2 * 2.0 + p1) * 6.0);
+
+        // the start is current point which is offset start of curve
+        let q0 = self.point;
+        let q3 = offset_point(p3, curve.end_direction(), distance);
+
+        self.push(Bezier::Cubic([
+            q0,
+            q0 + (p1 - p0) * start_scale,
+            q3 + (p2 - p3) * end_scale,
+            q3,
+        ]));
+    }
+
+    /// Adds join at corner between directions
+    fn corner(
+        &mut self,
+        vertex: Point2<f64>,
+        incoming: Vector2<f64>,
+        outgoing: Vector2<f64>,
+        distance: f64,
+    ) {
+        let incoming_normal = incoming.get_orthonormal(true, false);
+        let outgoing_normal = outgoing.get_orthonormal(true, false);
+        let end = vertex + outgoing_normal * distance;
+
+        let cross = incoming.normalize(false).cross(&outgoing.normalize(false));
+        let cos = incoming_normal.dot(&outgoing_normal);
+
+        if cross.abs() < 1e-9 && cos > 0.0 {
+            // smooth connection
+            self.line_to(end);
+            return;
+        }
+
+        if distance * cross > 0.0 {
+            // inner side of corner
+            self.pivot = Some(vertex);
+            self.point = end;
+            return;
+        }
+
+        match self.join {
+            LineJoin::Miter => {
+                if cos > -1.0 {
+                    let tip =
+                        vertex + (incoming_normal + outgoing_normal) * (distance / (1.0 + cos));
+                    if (tip - vertex).length() <= self.miter_limit * distance.abs() {
+                        self.line_to(tip);
+                    }
+                }
+            }
+            LineJoin::Round => {
+                let sweep = if cross.abs() < 1e-9 {
+                    // reversal of direction goes around the front side
+                    -PI * distance.signum()
+                } else {
+                    incoming_normal.cross(&outgoing_normal).atan2(cos)
+                };
+                self.arc(vertex, sweep);
+            }
+            LineJoin::Bevel => {}
+        }
+
+        self.line_to(end);
+    }
+
+    /// Adds cap at the end of open outline
+    ///
+    /// The cap goes from the left side to the right side of end point.
+    fn cap(&mut self, point: Point2<f64>, direction: Vector2<f64>, half_width: f64, cap: LineCap) {
+        let normal = direction.get_orthonormal(true, false) * half_width;
+        let forward = direction.normalize(false) * half_width;
+
+        match cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                self.line_to(point + normal + forward);
+                self.line_to(point - normal + forward);
+            }
+            LineCap::Round => self.arc(point, -PI),
+        }
+
+        self.line_to(point - normal);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Bound, EdgeColor, EdgeHolder, SegmentKind};
+
+    fn assert_bound(shape: &Shape, expected: Bound<f64>) {
+        let bound = shape.get_tight_bound();
+        assert!(
+            (bound.left - expected.left).abs() < 1e-3
+                && (bound.bottom - expected.bottom).abs() < 1e-3
+                && (bound.right - expected.right).abs() < 1e-3
+                && (bound.top - expected.top).abs() < 1e-3,
+            "{bound:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn closed_contour() {
+        let square = Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z").unwrap();
+
+        let stroke = square.stroke(2.0, LineJoin::Miter, LineCap::Butt, 2.0);
+        assert_eq!(stroke.contours().len(), 2);
+        assert!(stroke.validate());
+        assert_bound(&stroke, Bound::new(-1.0, -1.0, 11.0, 11.0));
+
+        // inner outline is trimmed at corners
+        let inner = &stroke.contours()[1];
+        assert_eq!(inner.edges().len(), 4);
+        assert_eq!(inner.winding(), -square.contours()[0].winding());
+        assert!(inner
+            .edges()
+            .iter()
+            .all(|edge| (edge.start_point().x - 1.0).abs() < 1e-9
+                || (edge.start_point().x - 9.0).abs() < 1e-9));
+
+        // miter is beveled when exceeds limit
+        let stroke = square.stroke(2.0, LineJoin::Miter, LineCap::Butt, 1.0);
+        assert_eq!(stroke.contours()[0].edges().len(), 8);
+        assert_eq!(
+            square
+                .stroke(2.0, LineJoin::Bevel, LineCap::Butt, 2.0)
+                .contours()[0]
+                .edges()
+                .len(),
+            8
+        );
+
+        let stroke = square.stroke(2.0, LineJoin::Round, LineCap::Butt, 0.0);
+        assert!(stroke.validate());
+        assert_bound(&stroke, Bound::new(-1.0, -1.0, 11.0, 11.0));
+        assert!(stroke.contours()[0]
+            .edges()
+            .iter()
+            .any(|edge| edge.segment_kind() == SegmentKind::Cubic));
+
+        assert!(square
+            .stroke(0.0, LineJoin::Miter, LineCap::Butt, 2.0)
+            .contours()
+            .is_empty());
+    }
+
+    #[test]
+    fn open_contour() {
+        let mut line = Shape::default();
+        line.add_contour_mut().add_edge(&EdgeHolder::new_linear(
+            (0.0, 0.0),
+            (10.0, 0.0),
+            EdgeColor::White,
+        ));
+
+        let stroke = line.stroke(2.0, LineJoin::Miter, LineCap::Butt, 2.0);
+        assert_eq!(stroke.contours().len(), 1);
+        assert_eq!(stroke.contours()[0].edges().len(), 4);
+        assert!(stroke.validate());
+        assert_bound(&stroke, Bound::new(0.0, -1.0, 10.0, 1.0));
+
+        let stroke = line.stroke(2.0, LineJoin::Miter, LineCap::Square, 2.0);
+        assert_bound(&stroke, Bound::new(-1.0, -1.0, 11.0, 1.0));
+
+        let stroke = line.stroke(2.0, LineJoin::Miter, LineCap::Round, 2.0);
+        assert!(stroke.validate());
+        assert_bound(&stroke, Bound::new(-1.0, -1.0, 11.0, 1.0));
+    }
+
+    #[test]
+    fn offset() {
+        let filled = |shape: &Shape, x: f64, y: f64| shape.scanline(y).filled(x, FillRule::NonZero);
+        let square = Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z").unwrap();
+
+        let bold = square.offset(1.0, LineJoin::Miter);
+        assert_eq!(bold.contours().len(), 1);
+        assert!(bold.validate());
+        assert_eq!(bold.contours()[0].winding(), square.contours()[0].winding());
+        assert_bound(&bold, Bound::new(-1.0, -1.0, 11.0, 11.0));
+
+        let thin = square.offset(-1.0, LineJoin::Miter);
+        assert_bound(&thin, Bound::new(1.0, 1.0, 9.0, 9.0));
+        assert!(square.offset(-6.0, LineJoin::Miter).contours().is_empty());
+
+        // orientation of contours doesn't matter
+        let reversed = Shape::from_svg_path_data("M0 0 L10 0 L10 10 L0 10 Z").unwrap();
+        assert_bound(
+            &reversed.offset(1.0, LineJoin::Round),
+            Bound::new(-1.0, -1.0, 11.0, 11.0),
+        );
+
+        // the hole is shrinked
+        let frame =
+            Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z M3 3 L7 3 L7 7 L3 7 Z").unwrap();
+        let bold = frame.offset(1.0, LineJoin::Bevel);
+        assert_eq!(bold.contours().len(), 2);
+        assert!(filled(&bold, 3.5, 5.0) && !filled(&bold, 5.0, 5.0));
+        assert!(filled(&frame.offset(2.5, LineJoin::Miter), 5.0, 5.0));
+    }
+
+    #[test]
+    fn curves() {
+        let circle =
+            Shape::from_svg_path_data("M-10 0 A10 10 0 0 0 10 0 A10 10 0 0 0 -10 0 Z").unwrap();
+
+        let stroke = circle.stroke(4.0, LineJoin::Round, LineCap::Butt, 0.0);
+        assert_eq!(stroke.contours().len(), 2);
+        assert!(stroke.validate());
+        assert_bound(&stroke, Bound::new(-12.0, -12.0, 12.0, 12.0));
+        assert_bound(
+            &{
+                let mut inner = Shape::default();
+                inner.add_contour(&stroke.contours()[1]);
+                inner
+            },
+            Bound::new(-8.0, -8.0, 8.0, 8.0),
+        );
+
+        let bold = circle.offset(2.0, LineJoin::Miter);
+        assert_eq!(bold.contours().len(), 1);
+        assert_bound(&bold, Bound::new(-12.0, -12.0, 12.0, 12.0));
+    }
+}
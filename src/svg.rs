@@ -0,0 +1,969 @@
+use crate::{
+    Bound, Contour, EdgeColor, EdgeHolder, Point2, Polarity, SegmentKind, Shape, ShapeBuilder,
+    Vector2,
+};
+use core::{f64::consts::PI, fmt};
+
+/// Error of SVG path data parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SvgPathError {
+    /// Byte offset in path data where error occurred
+    pub position: usize,
+    /// Error description
+    pub message: &'static str,
+}
+
+impl fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for SvgPathError {}
+
+impl Shape {
+    /// Creates shape from SVG path data (the `d` attribute of `<path>` element)
+    ///
+    /// All path commands are supported in both absolute and relative forms.
+    /// Elliptical arcs are converted into cubic segments.
+    /// Open subpaths are closed implicitly like when path is filled.
+    ///
+    /// The coordinates are taken as is, so the Y axis of resulting shape points downward
+    /// like in SVG and the shape has inverse Y axis (see [`Shape::set_inverse_y_axis`]).
+    pub fn from_svg_path_data(data: &str) -> Result<Self, SvgPathError> {
+        PathParser::new(data).parse()
+    }
+}
+
+/// SVG document export config
+#[derive(Clone, Copy)]
+pub struct SvgDocumentConfig {
+    stroke_width: f64,
+    contour_marks: bool,
+    bound: bool,
+    miter_bound: Option<(f64, f64, Polarity)>,
+}
+
+impl AsRef<SvgDocumentConfig> for SvgDocumentConfig {
+    fn as_ref(&self) -> &SvgDocumentConfig {
+        self
+    }
+}
+
+impl AsMut<SvgDocumentConfig> for SvgDocumentConfig {
+    fn as_mut(&mut self) -> &mut SvgDocumentConfig {
+        self
+    }
+}
+
+impl Default for SvgDocumentConfig {
+    fn default() -> Self {
+        Self {
+            stroke_width: 0.0,
+            contour_marks: true,
+            bound: false,
+            miter_bound: None,
+        }
+    }
+}
+
+impl SvgDocumentConfig {
+    /// Get width of edge strokes (in shape units)
+    #[inline(always)]
+    pub fn get_stroke_width(&self) -> f64 {
+        self.stroke_width
+    }
+
+    /// Set width of edge strokes (in shape units)
+    ///
+    /// Zero means the width is selected relative to the size of shape.
+    #[inline(always)]
+    pub fn set_stroke_width(&mut self, stroke_width: f64) {
+        self.stroke_width = stroke_width;
+    }
+
+    /// Configure width of edge strokes (in shape units)
+    #[inline(always)]
+    pub fn with_stroke_width(mut self, stroke_width: f64) -> Self {
+        self.set_stroke_width(stroke_width);
+        self
+    }
+
+    /// Get whether start points and directions of contours are marked
+    #[inline(always)]
+    pub fn get_contour_marks(&self) -> bool {
+        self.contour_marks
+    }
+
+    /// Set whether start points and directions of contours are marked
+    ///
+    /// Each contour gets a dot at its start point and an arrow pointing along the first edge.
+    /// The arrows of contours with positive winding are filled, the others are hollow.
+    #[inline(always)]
+    pub fn set_contour_marks(&mut self, contour_marks: bool) {
+        self.contour_marks = contour_marks;
+    }
+
+    /// Configure whether start points and directions of contours are marked
+    #[inline(always)]
+    pub fn with_contour_marks(mut self, contour_marks: bool) -> Self {
+        self.set_contour_marks(contour_marks);
+        self
+    }
+
+    /// Get whether bounding box of shape is drawn
+    #[inline(always)]
+    pub fn get_bound(&self) -> bool {
+        self.bound
+    }
+
+    /// Set whether bounding box of shape is drawn
+    #[inline(always)]
+    pub fn set_bound(&mut self, bound: bool) {
+        self.bound = bound;
+    }
+
+    /// Configure whether bounding box of shape is drawn
+    #[inline(always)]
+    pub fn with_bound(mut self, bound: bool) -> Self {
+        self.set_bound(bound);
+        self
+    }
+
+    /// Get border, miter limit and polarity of drawn mitered bounding box
+    #[inline(always)]
+    pub fn get_miter_bound(&self) -> Option<(f64, f64, Polarity)> {
+        self.miter_bound
+    }
+
+    /// Set border, miter limit and polarity of drawn mitered bounding box
+    ///
+    /// See [`Shape::get_bound_miters`].
+    #[inline(always)]
+    pub fn set_miter_bound(&mut self, miter_bound: Option<(f64, f64, Polarity)>) {
+        self.miter_bound = miter_bound;
+    }
+
+    /// Configure border, miter limit and polarity of drawn mitered bounding box
+    #[inline(always)]
+    pub fn with_miter_bound(mut self, border: f64, miter_limit: f64, polarity: Polarity) -> Self {
+        self.set_miter_bound(Some((border, miter_limit, polarity)));
+        self
+    }
+}
+
+impl Shape {
+    /// Writes shape as SVG path data
+    ///
+    /// Each contour starts with `M` command and ends with `Z` command.
+    /// The coordinates are written as is, so [`Shape::from_svg_path_data`] restores the same edges
+    /// of closed contours. A contour with gaps between edges is written as several subpaths
+    /// (each gap starts new `M` command), which are restored as separate closed contours.
+    pub fn write_svg_path_data(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        for (index, contour) in self.contours().iter().enumerate() {
+            if index > 0 {
+                out.write_char(' ')?;
+            }
+            write_contour_path_data(out, contour)?;
+        }
+        Ok(())
+    }
+
+    /// Gets shape as SVG path data
+    ///
+    /// See [`Shape::write_svg_path_data`].
+    pub fn to_svg_path_data(&self) -> String {
+        let mut out = String::new();
+        self.write_svg_path_data(&mut out).unwrap();
+        out
+    }
+
+    /// Writes shape as SVG document for debugging
+    ///
+    /// The shape is filled with translucent gray and each edge is stroked in its color
+    /// over dark background. Optionally contour start points and directions, bounding box
+    /// and mitered bounding box are drawn (see [`SvgDocumentConfig`]).
+    ///
+    /// The Y axis is flipped unless the shape has inverse Y axis,
+    /// so the document looks like generated bitmaps.
+    pub fn write_svg_document(
+        &self,
+        out: &mut impl fmt::Write,
+        config: impl AsRef<SvgDocumentConfig>,
+    ) -> fmt::Result {
+        let config = config.as_ref();
+
+        let mut bound = self.get_tight_bound();
+        if bound.is_empty() {
+            bound = Bound::new(0.0, 0.0, 0.0, 0.0);
+        }
+
+        let miter_bound = config.miter_bound.map(|(border, miter_limit, polarity)| {
+            let mut miter_bound = Bound::new(
+                bound.left - border,
+                bound.bottom - border,
+                bound.right + border,
+                bound.top + border,
+            );
+            self.bound_miters(&mut miter_bound, border, miter_limit, polarity);
+            miter_bound
+        });
+
+        let size = (bound.right - bound.left).max(bound.top - bound.bottom);
+        let stroke_width = if config.stroke_width > 0.0 {
+            config.stroke_width
+        } else if size > 0.0 {
+            size / 200.0
+        } else {
+            1.0
+        };
+
+        let mut view = miter_bound.unwrap_or(bound);
+        let margin = stroke_width * 8.0;
+        view.left -= margin;
+        view.bottom -= margin;
+        view.right += margin;
+        view.top += margin;
+
+        let flip = !self.get_inverse_y_axis();
+        let (view_y, transform) = if flip {
+            (-view.top, r#" transform="scale(1 -1)""#)
+        } else {
+            (view.bottom, "")
+        };
+
+        write!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            view.left,
+            view_y,
+            view.right - view.left,
+            view.top - view.bottom,
+        )?;
+        write!(
+            out,
+            r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#303030"/>"##,
+            view.left,
+            view_y,
+            view.right - view.left,
+            view.top - view.bottom,
+        )?;
+
+        write!(out, r#"<g{transform} stroke-width="{stroke_width}">"#)?;
+
+        out.write_str(r##"<path fill="#808080" fill-opacity="0.5" d=""##)?;
+        self.write_svg_path_data(out)?;
+        out.write_str(r#""/>"#)?;
+
+        if config.bound {
+            write_bound_rect(out, &bound, "#00ffff")?;
+        }
+
+        if let Some(miter_bound) = &miter_bound {
+            write_bound_rect(out, miter_bound, "#ffff00")?;
+        }
+
+        out.write_str(r#"<g fill="none" stroke-linecap="round">"#)?;
+        for contour in self.contours() {
+            for edge in contour.edges() {
+                write!(
+                    out,
+                    r#"<path stroke="{}" d=""#,
+                    edge_color_css(*edge.edge_color())
+                )?;
+                write_edge_path_data(out, edge, true)?;
+                out.write_str(r#""/>"#)?;
+            }
+        }
+        out.write_str("</g>")?;
+
+        if config.contour_marks {
+            out.write_str(r##"<g stroke="#ffffff">"##)?;
+            for contour in self.contours() {
+                if let Some(edge) = contour.edges().first() {
+                    write_contour_mark(out, edge, contour.winding(), stroke_width)?;
+                }
+            }
+            out.write_str("</g>")?;
+        }
+
+        out.write_str("</g></svg>")
+    }
+
+    /// Gets shape as SVG document for debugging
+    ///
+    /// See [`Shape::write_svg_document`].
+    pub fn to_svg_document(&self, config: impl AsRef<SvgDocumentConfig>) -> String {
+        let mut out = String::new();
+        self.write_svg_document(&mut out, config).unwrap();
+        out
+    }
+}
+
+fn write_contour_path_data(out: &mut impl fmt::Write, contour: &Contour) -> fmt::Result {
+    let mut point = None;
+    for edge in contour.edges() {
+        let start_point = *edge.start_point();
+        if point != Some(start_point) {
+            if point.is_some() {
+                out.write_char(' ')?;
+            }
+            write!(out, "M{} {}", start_point.x, start_point.y)?;
+        }
+        out.write_char(' ')?;
+        write_edge_path_data(out, edge, false)?;
+        point = Some(*edge.end_point());
+    }
+    if point.is_some() {
+        out.write_str(" Z")?;
+    }
+    Ok(())
+}
+
+fn write_edge_path_data(
+    out: &mut impl fmt::Write,
+    edge: &EdgeHolder,
+    move_to: bool,
+) -> fmt::Result {
+    if move_to {
+        let start_point = edge.start_point();
+        write!(out, "M{} {} ", start_point.x, start_point.y)?;
+    }
+    let end_point = edge.end_point();
+    match edge.segment_kind() {
+        SegmentKind::Linear => write!(out, "L{} {}", end_point.x, end_point.y),
+        SegmentKind::Quadratic => {
+            let cpoint = edge.control_point(0).unwrap();
+            write!(
+                out,
+                "Q{} {} {} {}",
+                cpoint.x, cpoint.y, end_point.x, end_point.y
+            )
+        }
+        SegmentKind::Cubic => {
+            let c1point = edge.control_point(0).unwrap();
+            let c2point = edge.control_point(1).unwrap();
+            write!(
+                out,
+                "C{} {} {} {} {} {}",
+                c1point.x, c1point.y, c2point.x, c2point.y, end_point.x, end_point.y
+            )
+        }
+    }
+}
+
+fn write_bound_rect(out: &mut impl fmt::Write, bound: &Bound<f64>, color: &str) -> fmt::Result {
+    write!(
+        out,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="{}"/>"#,
+        bound.left,
+        bound.bottom,
+        bound.right - bound.left,
+        bound.top - bound.bottom,
+        color,
+    )
+}
+
+fn write_contour_mark(
+    out: &mut impl fmt::Write,
+    edge: &EdgeHolder,
+    winding: i32,
+    stroke_width: f64,
+) -> fmt::Result {
+    let start_point = *edge.start_point();
+    let direction = (*edge.control_point(0).unwrap_or(edge.end_point()) - start_point)
+        .normalize(false)
+        * (stroke_width * 6.0);
+    let side = direction.get_orthogonal(true) * 0.5;
+    let tip = start_point + direction;
+    let left = start_point + side;
+    let right = start_point - side;
+
+    write!(
+        out,
+        r##"<circle cx="{}" cy="{}" r="{}" fill="#ffffff"/>"##,
+        start_point.x,
+        start_point.y,
+        stroke_width * 1.5,
+    )?;
+    write!(
+        out,
+        r#"<path fill="{}" data-winding="{}" d="M{} {} L{} {} L{} {} Z"/>"#,
+        if winding > 0 { "#ffffff" } else { "none" },
+        winding,
+        left.x,
+        left.y,
+        tip.x,
+        tip.y,
+        right.x,
+        right.y,
+    )
+}
+
+fn edge_color_css(color: EdgeColor) -> &'static str {
+    match color {
+        EdgeColor::Black => "#000000",
+        EdgeColor::Red => "#ff0000",
+        EdgeColor::Green => "#00ff00",
+        EdgeColor::Yellow => "#ffff00",
+        EdgeColor::Blue => "#0000ff",
+        EdgeColor::Magenta => "#ff00ff",
+        EdgeColor::Cyan => "#00ffff",
+        EdgeColor::White => "#ffffff",
+    }
+}
+
+struct PathParser<'a> {
+    data: &'a [u8],
+    position: usize,
+    builder: ShapeBuilder,
+    point: Point2<f64>,
+    start_point: Point2<f64>,
+    /// Last control point of cubic (`true`) or quadratic (`false`) curve
+    control_point: Option<(bool, Point2<f64>)>,
+}
+
+impl<'a> PathParser<'a> {
+    fn new(data: &'a str) -> Self {
+        Self {
+            data: data.as_bytes(),
+            position: 0,
+            builder: ShapeBuilder::new(),
+            point: Point2::default(),
+            start_point: Point2::default(),
+            control_point: None,
+        }
+    }
+
+    fn error<T>(&self, message: &'static str) -> Result<T, SvgPathError> {
+        Err(SvgPathError {
+            position: self.position,
+            message,
+        })
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0c')) {
+            self.position += 1;
+        }
+    }
+
+    fn skip_separator(&mut self) {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.position += 1;
+            self.skip_whitespace();
+        }
+    }
+
+    fn has_number(&self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Result<f64, SvgPathError> {
+        let start = self.position;
+        let digits = |parser: &mut Self| {
+            let start = parser.position;
+            while matches!(parser.peek(), Some(b'0'..=b'9')) {
+                parser.position += 1;
+            }
+            parser.position > start
+        };
+
+        if matches!(self.peek(), Some(b'-' | b'+')) {
+            self.position += 1;
+        }
+
+        let mut has_digits = digits(self);
+
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            has_digits |= digits(self);
+        }
+
+        if !has_digits {
+            self.position = start;
+            return self.error("expected number");
+        }
+
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mantissa_end = self.position;
+            self.position += 1;
+            if matches!(self.peek(), Some(b'-' | b'+')) {
+                self.position += 1;
+            }
+            if !digits(self) {
+                // not an exponent
+                self.position = mantissa_end;
+            }
+        }
+
+        let value = core::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok());
+
+        match value {
+            Some(value) => {
+                self.skip_separator();
+                Ok(value)
+            }
+            None => {
+                self.position = start;
+                self.error("invalid number")
+            }
+        }
+    }
+
+    fn flag(&mut self) -> Result<bool, SvgPathError> {
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return self.error("expected flag"),
+        };
+        self.position += 1;
+        self.skip_separator();
+        Ok(flag)
+    }
+
+    fn point(&mut self, relative: bool) -> Result<Point2<f64>, SvgPathError> {
+        let x = self.number()?;
+        let y = self.number()?;
+        let point = Point2::new(x, y);
+        Ok(if relative { point + self.point } else { point })
+    }
+
+    fn parse(mut self) -> Result<Shape, SvgPathError> {
+        self.skip_whitespace();
+
+        let mut started = false;
+
+        while let Some(command) = self.peek() {
+            if !command.is_ascii_alphabetic() {
+                return self.error("expected command");
+            }
+
+            let relative = command.is_ascii_lowercase();
+            let command = command.to_ascii_uppercase();
+
+            if !started && command != b'M' {
+                return self.error("path should start with moveto");
+            }
+            started = true;
+
+            if !b"MZLHVCSQTA".contains(&command) {
+                return self.error("unknown command");
+            }
+
+            self.position += 1;
+            self.skip_whitespace();
+
+            if command == b'Z' {
+                self.builder.close();
+                self.point = self.start_point;
+                self.control_point = None;
+                continue;
+            }
+
+            let mut command = command;
+
+            loop {
+                self.command(command, relative)?;
+
+                if !self.has_number() {
+                    break;
+                }
+
+                // subsequent pairs of moveto are implicit lineto commands
+                if command == b'M' {
+                    command = b'L';
+                }
+            }
+        }
+
+        let mut shape = self.builder.build();
+        shape.set_inverse_y_axis(true);
+
+        Ok(shape)
+    }
+
+    fn command(&mut self, command: u8, relative: bool) -> Result<(), SvgPathError> {
+        let mut control_point = None;
+
+        match command {
+            b'M' => {
+                let point = self.point(relative)?;
+                self.builder.move_to(point);
+                self.point = point;
+                self.start_point = point;
+            }
+            b'L' => {
+                let point = self.point(relative)?;
+                self.builder.line_to(point);
+                self.point = point;
+            }
+            b'H' => {
+                let x = self.number()? + if relative { self.point.x } else { 0.0 };
+                let y = self.point.y;
+                self.builder.line_to((x, y));
+                self.point.x = x;
+            }
+            b'V' => {
+                let x = self.point.x;
+                let y = self.number()? + if relative { self.point.y } else { 0.0 };
+                self.builder.line_to((x, y));
+                self.point.y = y;
+            }
+            b'C' | b'S' => {
+                let control_point1 = if command == b'C' {
+                    self.point(relative)?
+                } else {
+                    self.reflected_control_point(true)
+                };
+                let control_point2 = self.point(relative)?;
+                let point = self.point(relative)?;
+                self.builder.curve_to(control_point1, control_point2, point);
+                self.point = point;
+                control_point = Some((true, control_point2));
+            }
+            b'Q' | b'T' => {
+                let control_point1 = if command == b'Q' {
+                    self.point(relative)?
+                } else {
+                    self.reflected_control_point(false)
+                };
+                let point = self.point(relative)?;
+                self.builder.quad_to(control_point1, point);
+                self.point = point;
+                control_point = Some((false, control_point1));
+            }
+            b'A' => {
+                let rx = self.number()?;
+                let ry = self.number()?;
+                let rotation = self.number()?;
+                let large_arc = self.flag()?;
+                let sweep = self.flag()?;
+                let point = self.point(relative)?;
+                let start_point = self.point;
+                arc_to(
+                    &mut self.builder,
+                    start_point,
+                    Vector2::new(rx, ry),
+                    rotation,
+                    large_arc,
+                    sweep,
+                    point,
+                );
+                self.point = point;
+            }
+            _ => unreachable!(),
+        }
+
+        self.control_point = control_point;
+
+        Ok(())
+    }
+
+    /// Reflects last control point of curve of same kind about current point
+    fn reflected_control_point(&self, cubic: bool) -> Point2<f64> {
+        match self.control_point {
+            Some((kind, point)) if kind == cubic => self.point * 2.0 - point,
+            _ => self.point,
+        }
+    }
+}
+
+/// Appends elliptical arc as cubic segments
+fn arc_to(
+    builder: &mut ShapeBuilder,
+    start_point: Point2<f64>,
+    radius: Vector2<f64>,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    end_point: Point2<f64>,
+) {
+    if start_point == end_point {
+        return;
+    }
+
+    let (mut rx, mut ry) = (radius.x.abs(), radius.y.abs());
+
+    if rx == 0.0 || ry == 0.0 {
+        builder.line_to(end_point);
+        return;
+    }
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+
+    // endpoint to center parameterization (SVG spec, appendix B.2.4)
+    let half = (start_point - end_point) * 0.5;
+    let x1 = cos * half.x + sin * half.y;
+    let y1 = -sin * half.x + cos * half.y;
+
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut coefficient = (numerator / denominator).max(0.0).sqrt();
+    if large_arc == sweep {
+        coefficient = -coefficient;
+    }
+
+    let cx1 = coefficient * rx * y1 / ry;
+    let cy1 = -coefficient * ry * x1 / rx;
+
+    let middle = (start_point + end_point) * 0.5;
+    let center = Point2::new(
+        cos * cx1 - sin * cy1 + middle.x,
+        sin * cx1 + cos * cy1 + middle.y,
+    );
+
+    let angle =
+        |u: Vector2<f64>, v: Vector2<f64>| (u.x * v.y - u.y * v.x).atan2(u.x * v.x + u.y * v.y);
+
+    let start_vector = Vector2::new((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let end_vector = Vector2::new((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+
+    let start_angle = angle(Vector2::new(1.0, 0.0), start_vector);
+    let mut sweep_angle = angle(start_vector, end_vector);
+
+    if !sweep && sweep_angle > 0.0 {
+        sweep_angle -= 2.0 * PI;
+    } else if sweep && sweep_angle < 0.0 {
+        sweep_angle += 2.0 * PI;
+    }
+
+    // split arc into parts of at most quarter of turn
+    let parts = (sweep_angle.abs() / (0.5 * PI) - 1e-9).ceil().max(1.0) as usize;
+    let delta = sweep_angle / parts as f64;
+    let handle = 4.0 / 3.0 * (0.25 * delta).tan();
+
+    let ellipse_point = |angle: f64| {
+        let (sin_angle, cos_angle) = angle.sin_cos();
+        let (x, y) = (rx * cos_angle, ry * sin_angle);
+        Point2::new(center.x + cos * x - sin * y, center.y + sin * x + cos * y)
+    };
+
+    let ellipse_derivative = |angle: f64| {
+        let (sin_angle, cos_angle) = angle.sin_cos();
+        let (x, y) = (-rx * sin_angle, ry * cos_angle);
+        Vector2::new(cos * x - sin * y, sin * x + cos * y)
+    };
+
+    for part in 0..parts {
+        let angle1 = start_angle + delta * part as f64;
+        let angle2 = angle1 + delta;
+
+        let point1 = ellipse_point(angle1);
+        let point2 = if part + 1 == parts {
+            end_point
+        } else {
+            ellipse_point(angle2)
+        };
+
+        let control_point1 = point1 + ellipse_derivative(angle1) * handle;
+        let control_point2 = point2 - ellipse_derivative(angle2) * handle;
+
+        builder.curve_to(control_point1, control_point2, point2);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lines() {
+        let shape = Shape::from_svg_path_data("M0 0 H10 V10 H0 Z m20,0 l10 0 0 10 -10 0z").unwrap();
+
+        assert_eq!(shape.contours().len(), 2);
+        assert_eq!(shape.contours()[0].edges().len(), 4);
+        assert_eq!(shape.contours()[1].edges().len(), 4);
+        assert!(shape.validate());
+        assert_eq!(shape.get_tight_bound(), Bound::new(0.0, 0.0, 30.0, 10.0));
+
+        let edge = &shape.contours()[1].edges()[1];
+        assert_eq!(*edge.start_point(), Point2::new(30.0, 0.0));
+        assert_eq!(*edge.end_point(), Point2::new(30.0, 10.0));
+    }
+
+    #[test]
+    fn implicit_close() {
+        let shape =
+            Shape::from_svg_path_data("M0,0 L10,0 L10,10 M20 20 M 30 30 L 40 30 40 40").unwrap();
+
+        assert_eq!(shape.contours().len(), 2);
+        assert_eq!(shape.contours()[0].edges().len(), 3);
+        assert!(shape.validate());
+    }
+
+    #[test]
+    fn degenerate_edges() {
+        let shape = Shape::from_svg_path_data("M0 0 L0 0 L10 0 h0 Q10 0 10 0 L10 10 Z").unwrap();
+
+        assert_eq!(shape.contours().len(), 1);
+        assert_eq!(shape.contours()[0].edges().len(), 3);
+        assert!(shape.validate());
+    }
+
+    #[test]
+    fn smooth_curves() {
+        let shape =
+            Shape::from_svg_path_data("M0 0 C0 10 10 10 10 0 S20 -10 20 0 Q25 5 30 0 T40 0 Z")
+                .unwrap();
+
+        let edges = shape.contours()[0].edges();
+        assert_eq!(edges.len(), 5);
+
+        assert_eq!(edges[1].segment_kind(), SegmentKind::Cubic);
+        assert_eq!(
+            *edges[1].control_point(0).unwrap(),
+            Point2::new(10.0, -10.0)
+        );
+
+        assert_eq!(edges[3].segment_kind(), SegmentKind::Quadratic);
+        assert_eq!(*edges[3].control_point(0).unwrap(), Point2::new(35.0, -5.0));
+    }
+
+    #[test]
+    fn arcs() {
+        let shape = Shape::from_svg_path_data("M0 0 A5 5 0 0 1 10 0 Z").unwrap();
+        let edges = shape.contours()[0].edges();
+
+        // half of circle is split into two quarters
+        assert_eq!(edges.len(), 3);
+        assert_eq!(*edges[1].end_point(), Point2::new(10.0, 0.0));
+
+        let bound = shape.get_tight_bound();
+        assert!((bound.bottom + 5.0).abs() < 1e-3);
+        assert!(bound.top.abs() < 1e-9);
+
+        // radii are scaled up when too small and flags may be written without separators
+        let shape = Shape::from_svg_path_data("M0 0 a1 1 0 00 10 0z").unwrap();
+        let bound = shape.get_tight_bound();
+        assert!((bound.top - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn numbers() {
+        let shape = Shape::from_svg_path_data("M.5.5L1e1-1.5e0,-2E+1.5z").unwrap();
+        let edges = shape.contours()[0].edges();
+
+        assert_eq!(*edges[0].start_point(), Point2::new(0.5, 0.5));
+        assert_eq!(*edges[0].end_point(), Point2::new(10.0, -1.5));
+        assert_eq!(*edges[1].end_point(), Point2::new(-20.0, 0.5));
+    }
+
+    #[test]
+    fn errors() {
+        let error = |data| Shape::from_svg_path_data(data).err().unwrap();
+
+        assert_eq!(error("L0 0").position, 0);
+        assert_eq!(error("M0 0 L10").position, 8);
+        assert_eq!(error("M0 0 L10 x").position, 9);
+        assert_eq!(error("M0 0 X10 10").position, 5);
+        assert_eq!(error("M0 0 A1 1 0 2 0 1 1").position, 12);
+        assert_eq!(error("M0 0 A1 1 0 2 0 1 1").message, "expected flag");
+    }
+
+    #[test]
+    fn path_data_export() {
+        let data = "M0 0 L10 0 Q15 5 10 10 C5 15 0 15 0 10 L0 0 Z M20 0 L30 0 L25 10 L20 0 Z";
+        let shape = Shape::from_svg_path_data(data).unwrap();
+
+        assert_eq!(shape.to_svg_path_data(), data);
+
+        let restored = Shape::from_svg_path_data(&shape.to_svg_path_data()).unwrap();
+        assert_eq!(restored.contours().len(), 2);
+        assert_eq!(restored.contours()[0].edges().len(), 4);
+        assert_eq!(
+            restored.contours()[0].edges()[2].segment_kind(),
+            SegmentKind::Cubic
+        );
+
+        assert_eq!(Shape::default().to_svg_path_data(), "");
+    }
+
+    #[test]
+    fn disconnected_path_data_export() {
+        let mut contour = Contour::default();
+        for (start, end) in [((0.0, 0.0), (10.0, 0.0)), ((10.0, 0.0), (10.0, 10.0))] {
+            contour.add_edge(&EdgeHolder::new_linear(start, end, EdgeColor::default()));
+        }
+        // gap between (10, 10) and (0, 10)
+        contour.add_edge(&EdgeHolder::new_linear(
+            (0.0, 10.0),
+            (0.0, 0.0),
+            EdgeColor::default(),
+        ));
+        let mut shape = Shape::default();
+        shape.add_contour(&contour);
+
+        let data = shape.to_svg_path_data();
+        assert_eq!(data, "M0 0 L10 0 L10 10 M0 10 L0 0 Z");
+
+        // each subpath is closed separately
+        let restored = Shape::from_svg_path_data(&data).unwrap();
+        assert_eq!(restored.contours().len(), 2);
+        assert_eq!(restored.contours()[0].edges().len(), 3);
+        assert_eq!(restored.contours()[1].edges().len(), 2);
+    }
+
+    #[test]
+    fn document_orientation() {
+        // triangle pointing downward in SVG coordinates
+        let data = "M0 0 L10 0 L5 10 L0 0 Z";
+        let shape = Shape::from_svg_path_data(data).unwrap();
+        assert!(shape.get_inverse_y_axis());
+
+        // path data is written as is without flipping, so the document looks like source path
+        let document = shape.to_svg_document(SvgDocumentConfig::default());
+        assert!(!document.contains("scale(1 -1)"));
+        assert!(document.contains(&format!(r#"d="{data}""#)));
+
+        let restored = Shape::from_svg_path_data(&shape.to_svg_path_data()).unwrap();
+        assert!(restored.get_inverse_y_axis());
+        assert_eq!(restored.to_svg_path_data(), data);
+    }
+
+    #[test]
+    fn document_export() {
+        let mut shape = Shape::from_svg_path_data("M0 0 L0 10 L10 10 L10 0 Z").unwrap();
+        *shape.contours_mut()[0].edges_mut()[1].edge_color_mut() = EdgeColor::Magenta;
+
+        let document = shape.to_svg_document(SvgDocumentConfig::default().with_stroke_width(0.5));
+        assert!(document
+            .starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-4 -4 18 18">"#));
+        assert!(document.contains(r##"<path stroke="#ff00ff" d="M0 10 L10 10"/>"##));
+        assert!(document.contains(r#"data-winding="1""#));
+        assert!(document.ends_with("</svg>"));
+
+        // shapes with Y axis pointing upward are flipped
+        shape.set_inverse_y_axis(false);
+        let document = shape.to_svg_document(SvgDocumentConfig::default().with_stroke_width(0.5));
+        assert!(document
+            .starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-4 -14 18 18">"#));
+        assert!(document.contains(r#"transform="scale(1 -1)""#));
+
+        let document = shape.to_svg_document(
+            SvgDocumentConfig::default()
+                .with_stroke_width(0.5)
+                .with_contour_marks(false)
+                .with_bound(true)
+                .with_miter_bound(1.0, 2.0, Polarity::Positive),
+        );
+        assert!(!document.contains("data-winding"));
+        assert!(document.contains(
+            r##"<rect x="0" y="0" width="10" height="10" fill="none" stroke="#00ffff"/>"##
+        ));
+        assert!(document.contains(r##"stroke="#ffff00"/>"##));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{Affine2, FontMetricsExt, Point2, Shape};
+
+/// Creates single shape for text
+///
+/// Glyphs are placed along baseline starting from the origin using glyph advances
+/// and kerning of font. Line feed moves pen to the start of next line using line height of font.
+/// Characters which are missing in font are skipped.
+///
+/// The `size` is the em size in units of resulting shape.
+///
+/// The resulting shape should be normalized and edge colored before generation.
+pub fn text_shape<F>(font: &F, text: &str, size: f64) -> Shape
+where
+    F: FontMetricsExt,
+    F::Glyph: Copy,
+{
+    let scale = size / font.units_per_em();
+    let line_height = font.font_metrics().line_height;
+
+    let mut shape = Shape::default();
+    let mut pen = Point2::new(0.0, 0.0);
+    let mut prev_glyph = None;
+
+    for chr in text.chars() {
+        if chr == '\n' {
+            pen = Point2::new(0.0, pen.y - line_height);
+            prev_glyph = None;
+            continue;
+        }
+
+        let glyph = if let Some(glyph) = font.glyph_index(chr) {
+            glyph
+        } else {
+            continue;
+        };
+
+        if let Some(prev_glyph) = prev_glyph {
+            pen.x += font.glyph_kerning(prev_glyph, glyph).unwrap_or_default();
+        }
+
+        if let Some(mut glyph_shape) = font.glyph_shape(glyph) {
+            glyph_shape.transform(&Affine2::translate(pen).then(Affine2::scale(scale, scale)));
+            for contour in glyph_shape.contours() {
+                shape.add_contour(contour);
+            }
+        }
+
+        pen.x += font.glyph_advance(glyph).unwrap_or_default();
+        prev_glyph = Some(glyph);
+    }
+
+    shape
+}
+
+#[cfg(test)]
+#[cfg(feature = "ttf-parser")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn text_layout() {
+        use crate::FontExt;
+        use notosans::REGULAR_TTF;
+        use ttf_parser::Face;
+
+        let font = Face::parse(REGULAR_TTF, 0).unwrap();
+        let units_per_em = FontMetricsExt::units_per_em(&font);
+        let a = font.glyph_index('A').unwrap();
+        let v = font.glyph_index('V').unwrap();
+
+        let shape = text_shape(&font, "AV", 32.0);
+        assert_eq!(
+            shape.contours().len(),
+            font.glyph_shape(a).unwrap().contours().len()
+                + font.glyph_shape(v).unwrap().contours().len()
+        );
+
+        let pen = font.glyph_advance(a).unwrap() + font.glyph_kerning(a, v).unwrap_or_default();
+        let expected =
+            (pen + font.glyph_shape(v).unwrap().get_tight_bound().right) * 32.0 / units_per_em;
+        assert!((shape.get_tight_bound().right - expected).abs() < 1e-9);
+
+        let shape = text_shape(&font, "A\nA", 32.0);
+        let line_height = font.font_metrics().line_height * 32.0 / units_per_em;
+        let bound = shape.get_tight_bound();
+        let glyph_bound = text_shape(&font, "A", 32.0).get_tight_bound();
+        assert!((bound.bottom - (glyph_bound.bottom - line_height)).abs() < 1e-9);
+        assert!((bound.right - glyph_bound.right).abs() < 1e-9);
+    }
+}
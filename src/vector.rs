@@ -6,6 +6,7 @@ pub type Point2<T> = Vector2<T>;
 
 /// Generic 2D vector
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Vector2<T> {
     pub x: T,
@@ -270,4 +271,14 @@ impl Vector2<f64> {
     pub fn project(&self, vector: &Self, positive: bool) -> Self {
         unsafe { core::mem::transmute(self.as_raw().project(vector.as_raw(), positive)) }
     }
+
+    /// Computes dot product of vectors
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Computes cross product of vectors (the Z component of 3D cross product)
+    pub fn cross(&self, other: &Self) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
 }
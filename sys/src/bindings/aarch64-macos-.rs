@@ -2368,6 +2368,22 @@ extern "C" {
     #[link_name = "\u{1}__ZN7msdfgen19Scanline_destructorERNS_8ScanlineE"]
     pub fn msdfgen_Scanline_destructor(self_: *mut msdfgen_Scanline);
 }
+extern "C" {
+    #[link_name = "\u{1}__ZN7msdfgen18Contour_edges_dataERKNS_7ContourE"]
+    pub fn msdfgen_Contour_edges_data(self_: *const msdfgen_Contour) -> *const msdfgen_EdgeHolder;
+}
+extern "C" {
+    #[link_name = "\u{1}__ZN7msdfgen17Contour_edges_lenERKNS_7ContourE"]
+    pub fn msdfgen_Contour_edges_len(self_: *const msdfgen_Contour) -> usize;
+}
+extern "C" {
+    #[link_name = "\u{1}__ZN7msdfgen19Shape_contours_dataERKNS_5ShapeE"]
+    pub fn msdfgen_Shape_contours_data(self_: *const msdfgen_Shape) -> *const msdfgen_Contour;
+}
+extern "C" {
+    #[link_name = "\u{1}__ZN7msdfgen18Shape_contours_lenERKNS_5ShapeE"]
+    pub fn msdfgen_Shape_contours_len(self_: *const msdfgen_Shape) -> usize;
+}
 extern "C" {
     #[link_name = "\u{1}__ZN7msdfgen21EdgeHolder_setSegmentERNS_10EdgeHolderERKNS_11EdgeSegmentE"]
     pub fn msdfgen_EdgeHolder_setSegment(
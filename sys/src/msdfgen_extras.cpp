@@ -17,6 +17,22 @@ namespace msdfgen {
     self.~Scanline();
   }
 
+  const EdgeHolder *Contour_edges_data(const Contour &self) {
+    return self.edges.data();
+  }
+
+  size_t Contour_edges_len(const Contour &self) {
+    return self.edges.size();
+  }
+
+  const Contour *Shape_contours_data(const Shape &self) {
+    return self.contours.data();
+  }
+
+  size_t Shape_contours_len(const Shape &self) {
+    return self.contours.size();
+  }
+
   void EdgeHolder_setSegment(EdgeHolder &self, const EdgeSegment &newSegment) {
     EdgeSegment* segment = self;
     if (segment != nullptr) {
@@ -7,6 +7,14 @@ namespace msdfgen {
 
   void Scanline_destructor(Scanline &self);
 
+  const EdgeHolder *Contour_edges_data(const Contour &self);
+
+  size_t Contour_edges_len(const Contour &self);
+
+  const Contour *Shape_contours_data(const Shape &self);
+
+  size_t Shape_contours_len(const Shape &self);
+
   void EdgeHolder_setSegment(EdgeHolder &self, const EdgeSegment &segment);
 
   enum SegmentKind {